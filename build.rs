@@ -0,0 +1,88 @@
+//! Generates tests from the example manifests in `data/examples`, e.g. `data/examples/08.toml`.
+//! The `solution!` macro includes `$OUT_DIR/example_tests_<day>.rs` into the binary of a day.
+//! Days without a manifest get an empty file.
+use std::{env, fmt::Write, fs, path::Path};
+
+#[path = "src/template/manifest.rs"]
+// the tests of the module are only compiled into the library.
+#[allow(dead_code, unused_imports)]
+mod manifest;
+
+const EXAMPLES_DIR: &str = "data/examples";
+
+/// Turns a file name into a valid identifier, e.g. `08-a.txt` to `08_a`.
+fn sanitize(file: &str) -> String {
+    let stem = file.strip_suffix(".txt").unwrap_or(file);
+    stem.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn generate(examples: &[manifest::Example]) -> String {
+    // the module is included at the top of a solution by the `solution!` macro.
+    let mut code = String::from(
+        "#[cfg(test)]\n#[allow(clippy::items_after_test_module)]\nmod example_tests {\n    use super::*;\n",
+    );
+    let mut names: Vec<String> = vec![];
+
+    for example in examples {
+        let part = if example.part == 1 { "one" } else { "two" };
+
+        // keep test names unique if a file is listed more than once for a part.
+        let base = format!("part_{part}_{}", sanitize(&example.file));
+        let count = names.iter().filter(|n| **n == base).count();
+        names.push(base.clone());
+        let name = if count > 0 {
+            format!("{base}_{}", count + 1)
+        } else {
+            base
+        };
+
+        let path = format!("{EXAMPLES_DIR}/{}", example.file);
+        let _ = write!(
+            code,
+            r#"
+    #[test]
+    fn {name}() {{
+        let input = std::fs::read_to_string({path:?}).expect("could not open example file");
+        let result = part_{part}(&input).map(|r| r.to_string());
+        let expected = {expected:?};
+        assert!(
+            result.as_deref() == Some(expected),
+            "example {path}: expected {{expected}}, got {{}}",
+            result.as_deref().unwrap_or("None")
+        );
+    }}
+"#,
+            expected = example.expected,
+        );
+    }
+
+    code.push_str("}\n");
+    code
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={EXAMPLES_DIR}");
+    println!("cargo:rerun-if-changed=src/template/manifest.rs");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    for day in 1..=25 {
+        let manifest_path = Path::new(EXAMPLES_DIR).join(format!("{day:02}.toml"));
+
+        let code = match fs::read_to_string(&manifest_path) {
+            Ok(s) => match manifest::parse(&s) {
+                Ok(examples) => generate(&examples),
+                Err(e) => panic!("invalid manifest {}: {e}", manifest_path.display()),
+            },
+            Err(_) => String::new(),
+        };
+
+        fs::write(
+            Path::new(&out_dir).join(format!("example_tests_{day}.rs")),
+            code,
+        )
+        .unwrap();
+    }
+}
@@ -37,7 +37,7 @@ fn get_digits_with_words(line: &str) -> Vec<u32> {
         .collect();
 
     digits.append(&mut word_digits);
-    digits.sort_by(|(i, _), (j, _)| i.cmp(j));
+    digits.sort_by_key(|(i, _)| *i);
 
     digits.into_iter().map(|(_, b)| b).collect()
 }
@@ -1,4 +1,7 @@
-advent_of_code::solution!(2);
+advent_of_code::solution!(2, parse_input);
+
+use advent_of_code::combinator::{alt, literal, number, parse_all, separated, seq, ws};
+use advent_of_code::parse::{self, ParseError};
 
 #[derive(Default)]
 struct Colours {
@@ -13,42 +16,35 @@ fn game_is_possible(game_colours: &Colours, real_colours: &Colours) -> bool {
         && real_colours.blue >= game_colours.blue
 }
 
-fn parse_line(line: &str) -> (u32, Vec<Colours>) {
-    line.split_once(':')
-        .map(|(number, colours)| (parse_game_number(number), parse_all_colours(colours)))
-        .unwrap()
-}
+fn parse_line(line: &str) -> Result<(u32, Vec<Colours>), ParseError> {
+    let colour = seq((
+        ws(number::<u32>()),
+        ws(alt((literal("red"), literal("green"), literal("blue")))),
+    ));
+    let round = separated(colour, literal(","));
+    let game = seq((
+        literal("Game "),
+        number::<u32>(),
+        literal(":"),
+        separated(round, literal(";")),
+    ));
 
-fn parse_game_number(number: &str) -> u32 {
-    number
-        .strip_prefix("Game ")
-        .map(|number| number.parse::<u32>())
-        .unwrap()
-        .unwrap()
+    let (_, number, _, rounds) = parse_all(game, line)?;
+    Ok((number, rounds.into_iter().map(to_colours).collect()))
 }
 
-fn parse_all_colours(all_colours: &str) -> Vec<Colours> {
-    all_colours.split(';').map(parse_colours).collect()
+fn parse_input(input: &str) -> Vec<(u32, Vec<Colours>)> {
+    parse::lines_with(input, parse_line).unwrap_or_else(|err| panic!("invalid input: {err}"))
 }
 
-fn parse_colours(colours: &str) -> Colours {
-    colours.split(',').fold(Colours::default(), |acc, c| {
-        let (number, colour) = parse_colour(c);
-        match colour {
+fn to_colours(round: Vec<(u32, &str)>) -> Colours {
+    round
+        .into_iter()
+        .fold(Colours::default(), |acc, (number, colour)| match colour {
             "red" => Colours { red: number, ..acc },
             "green" => Colours { green: number, ..acc },
-            "blue" => Colours { blue: number, ..acc },
-            _ => acc,
-        }
-    })
-}
-
-fn parse_colour(colour: &str) -> (u32, &str) {
-    colour
-        .trim()
-        .split_once(' ')
-        .map(|(number, colour)| (number.parse::<u32>().unwrap(), colour))
-        .unwrap()
+            _ => Colours { blue: number, ..acc },
+        })
 }
 
 fn max_colours(colours: Vec<Colours>) -> Colours {
@@ -63,9 +59,8 @@ pub fn part_one(input: &str) -> Option<u32> {
     let real_colours = Colours { red: 12, green: 13, blue: 14 };
 
     Some(
-        input
-            .lines()
-            .map(parse_line)
+        parse_input(input)
+            .into_iter()
             .map(|(number, colours)| (number, max_colours(colours)))
             .filter(|(_, colours)| game_is_possible(colours, &real_colours))
             .map(|(number, _)| number)
@@ -75,9 +70,8 @@ pub fn part_one(input: &str) -> Option<u32> {
 
 pub fn part_two(input: &str) -> Option<u32> {
     Some(
-        input
-            .lines()
-            .map(parse_line)
+        parse_input(input)
+            .into_iter()
             .map(|(_, colours)| max_colours(colours))
             .map(|min_colours| min_colours.red * min_colours.green * min_colours.blue)
             .sum(),
@@ -1,98 +1,87 @@
-advent_of_code::solution!(3);
+advent_of_code::solution!(3, parse_input);
+
+use advent_of_code::grid::{Grid, Position};
 
 struct Number {
-    row: i32,
-    column: i32,
-    length: i32,
+    cells: Vec<Position>,
     value: u32,
 }
 
-struct Symbol {
-    row: i32,
-    column: i32,
-    maybe_gear: bool,
-}
-
 struct Engine {
+    grid: Grid<char>,
     numbers: Vec<Number>,
-    symbols: Vec<Symbol>,
+    /// The index of the number that covers a cell, if any.
+    owners: Grid<Option<usize>>,
 }
 
 fn parse_input(input: &str) -> Engine {
+    let grid = Grid::parse(input).unwrap();
     let mut numbers: Vec<Number> = Vec::new();
-    let mut symbols: Vec<Symbol> = Vec::new();
-
-    for (row, line) in input.lines().enumerate() {
-        let mut start = 0;
-        let mut finish = 0;
-        let mut in_number = false;
-
-        for (column, c) in line.chars().enumerate() {
-            match (in_number, c.is_ascii_digit()) {
-                (false, true) => {
-                    in_number = true;
-                    start = column;
-                    finish = column;
-                }
-                (true, true) => {
-                    finish = column;
-                }
-                (true, false) => {
-                    in_number = false;
-                    numbers.push(Number {
-                        row: row as i32,
-                        column: start as i32,
-                        length: (finish - start + 1) as i32,
-                        value: line[start..=finish].parse::<u32>().unwrap(),
-                    });
-                }
-                (false, false) => (),
+    let mut owners = grid.map(|_| None);
+
+    for (row, line) in grid.rows().enumerate() {
+        let mut column = 0;
+
+        while column < line.len() {
+            let length = line[column..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+
+            if length == 0 {
+                column += 1;
+                continue;
             }
 
-            if !in_number && c != '.' {
-                symbols.push(Symbol {
-                    row: row as i32,
-                    column: column as i32,
-                    maybe_gear: c == '*',
-                })
+            let cells: Vec<Position> = (column..column + length).map(|c| (row, c)).collect();
+            for &cell in &cells {
+                owners[cell] = Some(numbers.len());
             }
-        }
 
-        if in_number {
             numbers.push(Number {
-                row: row as i32,
-                column: start as i32,
-                length: (finish - start + 1) as i32,
-                value: line[start..=finish].parse::<u32>().unwrap(),
+                cells,
+                value: line[column..column + length]
+                    .iter()
+                    .collect::<String>()
+                    .parse::<u32>()
+                    .unwrap(),
             });
+
+            column += length;
         }
     }
 
-    Engine { numbers, symbols }
+    Engine { grid, numbers, owners }
 }
 
-fn is_any_adjacent(number: &Number, symbols: &[Symbol]) -> bool {
-    symbols.iter().any(|symbol| is_adjacent(number, symbol))
+fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
 }
 
-fn is_adjacent(number: &Number, symbol: &Symbol) -> bool {
-    symbol.row >= number.row - 1
-        && symbol.row <= number.row + 1
-        && symbol.column >= number.column - 1
-        && symbol.column <= number.column + number.length
+fn is_part_number(engine: &Engine, number: &Number) -> bool {
+    number.cells.iter().any(|&cell| {
+        engine
+            .grid
+            .neighbours8(cell)
+            .any(|neighbour| is_symbol(engine.grid[neighbour]))
+    })
 }
 
-fn gear_ratio(numbers: &[Number], maybe_gear: &Symbol) -> Option<u32> {
-    let adjacent_numbers = numbers
-        .iter()
-        .filter(|number| is_adjacent(number, maybe_gear))
-        .collect::<Vec<&Number>>();
+fn gear_ratio(engine: &Engine, maybe_gear: Position) -> Option<u32> {
+    let mut adjacent_numbers = engine
+        .grid
+        .neighbours8(maybe_gear)
+        .filter_map(|neighbour| engine.owners[neighbour])
+        .collect::<Vec<usize>>();
+
+    adjacent_numbers.sort_unstable();
+    adjacent_numbers.dedup();
 
     if adjacent_numbers.len() == 2 {
         Some(
             adjacent_numbers
                 .iter()
-                .map(|&number| number.value)
+                .map(|&index| engine.numbers[index].value)
                 .product::<u32>(),
         )
     } else {
@@ -106,8 +95,8 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(
         engine
             .numbers
-            .into_iter()
-            .filter(|number| is_any_adjacent(number, &engine.symbols))
+            .iter()
+            .filter(|number| is_part_number(&engine, number))
             .map(|number| number.value)
             .sum::<u32>(),
     )
@@ -118,10 +107,10 @@ pub fn part_two(input: &str) -> Option<u32> {
 
     Some(
         engine
-            .symbols
+            .grid
+            .find_all(|&c| c == '*')
             .into_iter()
-            .filter(|symbol| symbol.maybe_gear)
-            .flat_map(|maybe_gear| gear_ratio(&engine.numbers, &maybe_gear))
+            .flat_map(|maybe_gear| gear_ratio(&engine, maybe_gear))
             .sum::<u32>(),
     )
 }
@@ -1,82 +1,38 @@
-advent_of_code::solution!(4);
+advent_of_code::solution!(4, parse_input);
 
-use std::collections::HashMap;
+use advent_of_code::counter::Counter;
+use advent_of_code::memo::Memo;
+use advent_of_code::parse::{self, ParseError};
 
-fn parse_numbers(numbers: &str) -> Vec<u32> {
-    numbers
-        .split_whitespace()
-        .map(|n| n.trim())
-        .map(|n| n.parse::<u32>().unwrap())
-        .collect()
+fn parse_line(line: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    let (_, numbers) = parse::key_value(line, ":")?;
+    let (winning, mine) = parse::key_value(numbers, "|")?;
+    Ok((parse::ints(winning)?, parse::ints(mine)?))
 }
 
-fn parse_line(line: &str) -> (Vec<u32>, Vec<u32>) {
-    line.split_once(':')
-        .map(|(_, numbers)| numbers)
-        .map(|numbers| numbers.split_once('|').unwrap())
-        .map(|(winning, mine)| (parse_numbers(winning), parse_numbers(mine)))
-        .unwrap()
+fn parse_input(input: &str) -> Vec<(Vec<u32>, Vec<u32>)> {
+    parse::lines_with(input, parse_line).unwrap_or_else(|err| panic!("invalid input: {err}"))
 }
 
 fn count_winning_numbers(mine: &[u32], winning: &[u32]) -> u32 {
+    let winning: Counter<u32> = winning.iter().copied().collect();
     mine.iter()
         .filter(|&number| winning.contains(number))
         .count() as u32
 }
 
-// fn count_cards(
-//     card: &u32,
-//     max_card: &u32,
-//     cards: &HashMap<u32, Vec<u32>>,
-//     mut seen: &HashMap<u32, u32>,
-// ) -> u32 {
-//     if seen.contains_key(&card) {
-//         return *seen.get(&card).unwrap();
-//     }
-
-//     let next_cards = cards
-//         .get(card)
-//         .unwrap()
-//         .clone()
-//         .iter()
-//         .filter(|&c| c <= max_card)
-//         .collect::<u32>();
-
-//     if next_cards.is_empty() {
-//         return 0;
-//     }
-
-//     1
-// }
-
-fn count_cards(card: u32, cards: &[u32], seen: &mut HashMap<u32, u32>) -> u32 {
-    if let Some(exists) = seen.get(&card) {
-        return *exists;
-    }
-
-    let next_cards = ((card + 1)..(card + 1 + cards[(card - 1) as usize]))
+/// The number of cards won by `card`, including the cards those win in turn.
+fn count_cards(recurse: &mut dyn FnMut(u32) -> u32, card: u32, cards: &[u32]) -> u32 {
+    ((card + 1)..(card + 1 + cards[(card - 1) as usize]))
         .filter(|&c| c <= cards.len() as u32)
-        .collect::<Vec<_>>();
-
-    if next_cards.is_empty() {
-        return 0;
-    }
-
-    let total = next_cards
-        .into_iter()
-        .map(|next| 1 + count_cards(next, cards, seen))
-        .sum::<u32>();
-
-    seen.insert(card, total);
-
-    total
+        .map(|next| 1 + recurse(next))
+        .sum()
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
     Some(
-        input
-            .lines()
-            .map(parse_line)
+        parse_input(input)
+            .into_iter()
             .map(|(winning, mine)| count_winning_numbers(&mine, &winning))
             .map(|count| {
                 if count == 0 {
@@ -90,16 +46,16 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let cards = input
-        .lines()
-        .map(parse_line)
+    let cards = parse_input(input)
+        .into_iter()
         .map(|(winning, mine)| count_winning_numbers(&mine, &winning))
         .collect::<Vec<_>>();
 
-    let mut card_count = HashMap::<u32, u32>::new();
+    let mut memo = Memo::new();
+    let count = |recurse: &mut dyn FnMut(u32) -> u32, card| count_cards(recurse, card, &cards);
 
-    let total = (1..=cards.len())
-        .map(|card| count_cards(card as u32, &cards, &mut card_count))
+    let total = (1..=cards.len() as u32)
+        .map(|card| memo.solve(card, &count))
         .sum::<u32>();
 
     Some(cards.len() as u32 + total)
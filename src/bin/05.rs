@@ -1,16 +1,19 @@
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, parse_input);
 
-use core::ops::RangeInclusive;
+use core::ops::Range;
+
+use advent_of_code::intervals::RangeSet;
+use advent_of_code::parse::{self, ParseError};
 
 struct MappingRange {
-    source: RangeInclusive<u64>,
+    source: Range<u64>,
     destination_start: u64,
 }
 
 impl MappingRange {
     fn get_destination(&self, source: u64) -> Option<u64> {
         if self.source.contains(&source) {
-            Some(self.destination_start + (source - self.source.start()))
+            Some(self.destination_start + (source - self.source.start))
         } else {
             None
         }
@@ -30,6 +33,14 @@ impl Mapping {
             .next()
             .unwrap_or(source)
     }
+
+    fn get_destinations(&self, sources: &RangeSet<u64>) -> RangeSet<u64> {
+        sources.map_pieces(
+            self.ranges
+                .iter()
+                .map(|range| (range.source.clone(), range.destination_start)),
+        )
+    }
 }
 
 struct Almanac {
@@ -43,45 +54,45 @@ impl Almanac {
             .iter()
             .fold(seed, |acc, range| range.get_destination(acc))
     }
+
+    fn get_locations(&self, seeds: RangeSet<u64>) -> RangeSet<u64> {
+        self.mappings
+            .iter()
+            .fold(seeds, |acc, mapping| mapping.get_destinations(&acc))
+    }
 }
 
-fn parse_input(input: &str) -> Almanac {
-    let mut lines = input.lines();
-
-    let seeds = lines
-        .next()
-        .unwrap()
-        .strip_prefix("seeds: ")
-        .map(|s| s.split(' '))
-        .unwrap()
-        .map(|s| s.trim())
-        .map(|seed| seed.parse::<u64>().unwrap())
-        .collect::<Vec<u64>>();
-
-    lines.next();
-
-    let mappings = lines
-        .collect::<Vec<_>>()
-        .split(|&line| line.is_empty())
-        .map(|m| {
-            m.iter()
-                .skip(1)
-                .map(|&mapping| {
-                    mapping
-                        .split_whitespace()
-                        .map(|i| i.parse::<u64>().unwrap())
-                        .collect::<Vec<_>>()
-                })
-                .map(|range| MappingRange {
-                    source: range[1]..=range[1] + range[2],
-                    destination_start: range[0],
-                })
-                .collect::<Vec<_>>()
+fn parse_mapping(block: &str) -> Result<Mapping, ParseError> {
+    let ranges = block
+        .lines()
+        .skip(1)
+        .map(|line| match parse::ints::<u64>(line)?[..] {
+            [destination_start, source_start, length] => Ok(MappingRange {
+                source: source_start..source_start + length,
+                destination_start,
+            }),
+            _ => Err(ParseError::new(line, "expected 3 numbers")),
         })
-        .map(|ranges| Mapping { ranges })
-        .collect::<Vec<_>>();
+        .collect::<Result<_, _>>()?;
+
+    Ok(Mapping { ranges })
+}
+
+fn parse_almanac(input: &str) -> Result<Almanac, ParseError> {
+    let blocks = parse::split_blocks(input);
+    let (_, seeds) = parse::key_value(blocks.first().copied().unwrap_or(input), ":")?;
 
-    Almanac { seeds, mappings }
+    Ok(Almanac {
+        seeds: parse::ints(seeds)?,
+        mappings: blocks[1..]
+            .iter()
+            .map(|block| parse_mapping(block))
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn parse_input(input: &str) -> Almanac {
+    parse_almanac(input).unwrap_or_else(|err| panic!("invalid input: {}", err.locate(input)))
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
@@ -97,12 +108,13 @@ pub fn part_one(input: &str) -> Option<u64> {
 pub fn part_two(input: &str) -> Option<u64> {
     let almanac = parse_input(input);
 
-    almanac
+    let seeds = almanac
         .seeds
         .chunks_exact(2)
         .map(|c| c[0]..c[0] + c[1])
-        .flat_map(|seeds| seeds.map(|seed| almanac.get_location(seed)))
-        .min()
+        .collect();
+
+    almanac.get_locations(seeds).min()
 }
 
 #[cfg(test)]
@@ -1,16 +1,12 @@
-advent_of_code::solution!(6);
+advent_of_code::solution!(6, parse_input);
+
+use advent_of_code::parse;
 
 fn parse_input(input: &str) -> Vec<(u64, u64)> {
-    let lines = input
-        .lines()
-        .map(|line| line.split_once(':').unwrap())
-        .map(|(_, line)| line)
-        .map(str::split_whitespace)
-        .map(|line| {
-            line.map(|value| value.parse::<u64>().unwrap())
-                .collect::<Vec<u64>>()
-        })
-        .collect::<Vec<_>>();
+    let lines = parse::lines_with(input, |line| {
+        parse::ints::<u64>(parse::key_value(line, ":")?.1)
+    })
+    .unwrap_or_else(|err| panic!("invalid input: {err}"));
 
     lines[0]
         .iter()
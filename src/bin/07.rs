@@ -1,8 +1,8 @@
-use itertools::Itertools;
-use std::collections::HashMap;
-
 advent_of_code::solution!(7);
 
+use advent_of_code::counter::Counter;
+use itertools::Itertools;
+
 #[derive(PartialEq, PartialOrd, Eq, Ord)]
 enum HandType {
     Unknown,
@@ -23,50 +23,26 @@ struct Hand {
 }
 
 fn get_hand_type_with_jokers(hand: [u32; 5]) -> HandType {
-    if !hand.contains(&1) {
-        return get_hand_type(hand);
-    }
-
-    let joker_count = hand.iter().filter(|&&c| c == 1).count();
+    let cards: Counter<u32> = hand.iter().copied().filter(|&c| c != 1).collect();
 
-    if joker_count == 4 || joker_count == 5 {
-        return HandType::FiveOfAKind;
+    // jokers are worth the most as copies of the most common card.
+    match cards.most_common(1).first() {
+        Some(&(&mode, _)) => get_hand_type(hand.map(|v| if v == 1 { mode } else { v })),
+        None => HandType::FiveOfAKind,
     }
-
-    let non_jokers = hand.iter().filter(|&&c| c != 1).collect_vec();
-    let mut occurrences = HashMap::new();
-
-    for &value in non_jokers {
-        *occurrences.entry(value).or_insert(0) += 1;
-    }
-
-    let mode = occurrences
-        .into_iter()
-        .max_by_key(|&(_, count)| count)
-        .map(|(val, _)| val)
-        .unwrap();
-
-    get_hand_type(hand.map(|v| if v == 1 { mode } else { v }))
 }
 
 fn get_hand_type(hand: [u32; 5]) -> HandType {
-    let sorted_cards = itertools::sorted(hand).collect::<Vec<_>>();
-
-    let matching_cards = sorted_cards
-        .iter()
-        .group_by(|&x| x)
-        .into_iter()
-        .map(|(_, group)| group.count())
-        .collect::<Vec<_>>();
-
-    match matching_cards.iter().max() {
-        Some(1) => HandType::HighCard,
-        Some(2) if matching_cards.iter().filter(|&&c| c == 2).count() == 2 => HandType::TwoPair,
-        Some(2) => HandType::OnePair,
-        Some(3) if matching_cards.contains(&2) => HandType::FullHouse,
-        Some(3) => HandType::ThreeOfAKind,
-        Some(4) => HandType::FourOfAKind,
-        Some(5) => HandType::FiveOfAKind,
+    let cards: Counter<u32> = hand.into_iter().collect();
+
+    match cards.signature()[..] {
+        [1, 1, 1, 1, 1] => HandType::HighCard,
+        [2, 1, 1, 1] => HandType::OnePair,
+        [2, 2, 1] => HandType::TwoPair,
+        [3, 1, 1] => HandType::ThreeOfAKind,
+        [3, 2] => HandType::FullHouse,
+        [4, 1] => HandType::FourOfAKind,
+        [5] => HandType::FiveOfAKind,
         _ => HandType::Unknown,
     }
 }
@@ -1,116 +1,113 @@
+advent_of_code::solution!(8, parse_input);
+
 use std::collections::HashMap;
 
+use advent_of_code::combinator::{alt, delimited, identifier, literal, many, parse_all, seq, ws};
+use advent_of_code::cycles::{self, Goals};
+use advent_of_code::math;
+use advent_of_code::parse::{self, ParseError};
 use itertools::Itertools;
 
-advent_of_code::solution!(8);
-
-use itertools::FoldWhile::{Continue, Done};
-
-fn parse_input(input: &str) -> (&str, HashMap<&str, (&str, &str)>) {
-    let mut lines = input.lines();
-
-    let instructions = lines.next().unwrap();
-    lines.next();
-
-    let maps = lines
-        .map(|line| line.split_once(" = ").unwrap())
-        .map(|(node, directions)| {
-            (
-                node,
-                directions
-                    .split_once(", ")
-                    .map(|(l, r)| (&l[1..], &r[..3]))
-                    .unwrap(),
-            )
-        });
-
-    (instructions, HashMap::from_iter(maps))
+type Network<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+fn parse_node(line: &str) -> Result<(&str, (&str, &str)), ParseError> {
+    let node = seq((
+        identifier(),
+        ws(literal("=")),
+        ws(delimited(
+            literal("("),
+            seq((ws(identifier()), ws(literal(",")), ws(identifier()))),
+            ws(literal(")")),
+        )),
+    ));
+
+    let (name, _, (left, _, right)) = parse_all(node, line)?;
+    Ok((name, (left, right)))
 }
 
-fn steps_to_node(
-    instructions: &str,
-    maps: &HashMap<&str, (&str, &str)>,
-    start: &str,
-    found: fn(&str) -> bool,
-) -> u32 {
-    let (_, steps) = instructions
-        .chars()
-        .cycle()
-        .fold_while((start, 0), |(node, steps), dir| {
-            if found(node) {
-                Done((&node, steps))
-            } else {
-                let (l, r) = maps.get(node).unwrap();
-                Continue((if dir == 'L' { &l } else { &r }, steps + 1))
-            }
-        })
-        .into_inner();
-    steps
-}
+fn parse_network(input: &str) -> Result<(&str, Network<'_>), ParseError> {
+    let blocks = parse::split_blocks(input);
+    let (instructions, nodes) = match blocks[..] {
+        [instructions, nodes] => (instructions, nodes),
+        _ => return Err(ParseError::new(input, "expected instructions and nodes")),
+    };
+    parse_all(many(alt((literal("L"), literal("R")))), instructions)?;
 
-pub fn lcm(nums: &[u64]) -> u64 {
-    if nums.len() == 1 {
-        return nums[0];
-    }
-
-    let a = nums[0];
-    let b = lcm(&nums[1..]);
-
-    a * b / gcd(a, b)
+    let nodes = parse::lines_with(nodes, parse_node)?;
+    Ok((instructions, nodes.into_iter().collect()))
 }
 
-fn gcd(a: u64, b: u64) -> u64 {
-    if b == 0 {
-        return a;
-    }
+fn parse_input(input: &str) -> (&str, Network<'_>) {
+    parse_network(input).unwrap_or_else(|err| panic!("invalid input: {}", err.locate(input)))
+}
 
-    gcd(b, a % b)
+/// Walks the network from `start`. The state of a walk is the current node and the index of the
+/// next instruction, so it ends up in a cycle after at most `nodes * instructions` steps.
+fn walk<'a>(
+    instructions: &[u8],
+    maps: &Network<'a>,
+    start: &'a str,
+    found: fn(&str) -> bool,
+) -> Goals {
+    cycles::goals(
+        (start, 0),
+        |&(node, i)| {
+            let (l, r) = maps
+                .get(node)
+                .unwrap_or_else(|| panic!("node `{node}` is not defined"));
+            (
+                if instructions[i] == b'L' { l } else { r },
+                (i + 1) % instructions.len(),
+            )
+        },
+        |&(node, _)| found(node),
+    )
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
     let (instructions, maps) = parse_input(input);
 
-    Some(steps_to_node(instructions, &maps, "AAA", |s| s == "ZZZ"))
+    let steps = walk(instructions.as_bytes(), &maps, "AAA", |s| s == "ZZZ").first()?;
+    u32::try_from(steps).ok()
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
     let (instructions, maps) = parse_input(input);
 
-    let starting_nodes = maps.keys().filter(|key| key.ends_with('A')).collect_vec();
-
-    let cycles = starting_nodes
-        .iter()
-        .map(|start| steps_to_node(instructions, &maps, start, |s| s.ends_with('Z')))
+    let walks = maps
+        .keys()
+        .filter(|key| key.ends_with('A'))
+        .map(|start| walk(instructions.as_bytes(), &maps, start, |s| s.ends_with('Z')))
         .collect_vec();
 
-    Some(lcm(&cycles.iter().map(|&c| c as u64).collect_vec()))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, "a",
-        ));
-        assert_eq!(result, Some(2));
+    // until every walk has entered its cycle, check the steps one by one.
+    let settled = walks.iter().map(|w| w.cycle.start).max()?;
+    if let Some(steps) = (0..settled).find(|&step| walks.iter().all(|w| w.is_goal(step))) {
+        return u64::try_from(steps).ok();
     }
 
-    #[test]
-    fn test_part_one_b() {
-        let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, "b",
-        ));
-        assert_eq!(result, Some(6));
-    }
+    // afterwards, a walk is at an end node whenever the steps are congruent to one of its goals.
+    let to_i64 = |n: usize| i64::try_from(n).ok();
+    let settled = to_i64(settled)?;
+    let steps = walks
+        .iter()
+        .map(|w| {
+            w.in_cycle()
+                .map(|step| Some((to_i64(step)?, to_i64(w.cycle.length)?)))
+                .collect::<Option<Vec<_>>>()
+        })
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .multi_cartesian_product()
+        .filter_map(|congruences| math::crt(&congruences))
+        .map(|(x, modulus)| {
+            if x < settled {
+                x + (settled - x + modulus - 1) / modulus * modulus
+            } else {
+                x
+            }
+        })
+        .min()?;
 
-    #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, "c",
-        ));
-        assert_eq!(result, Some(6));
-    }
+    u64::try_from(steps).ok()
 }
@@ -1,6 +1,11 @@
+advent_of_code::solution!(9, parse_input);
+
+use advent_of_code::parse;
 use itertools::Itertools;
 
-advent_of_code::solution!(9);
+fn parse_input(input: &str) -> Vec<Vec<i32>> {
+    parse::lines_with(input, parse::ints).unwrap_or_else(|err| panic!("invalid input: {err}"))
+}
 
 fn get_difference(seq: &[i32]) -> Vec<i32> {
     seq.iter()
@@ -16,7 +21,7 @@ fn next_term(seq: &[i32]) -> i32 {
         return *seq.last().unwrap();
     }
 
-    return seq.last().unwrap() + next_term(&diff);
+    seq.last().unwrap() + next_term(&diff)
 }
 
 fn prev_term(seq: &[i32]) -> i32 {
@@ -26,35 +31,15 @@ fn prev_term(seq: &[i32]) -> i32 {
         return *seq.first().unwrap();
     }
 
-    return seq.first().unwrap() - prev_term(&diff);
+    seq.first().unwrap() - prev_term(&diff)
 }
 
 pub fn part_one(input: &str) -> Option<i32> {
-    Some(
-        input
-            .lines()
-            .map(|line| {
-                line.split_whitespace()
-                    .map(|n| n.parse::<i32>().unwrap())
-                    .collect_vec()
-            })
-            .map(|seq| next_term(seq.as_slice()))
-            .sum(),
-    )
+    Some(parse_input(input).iter().map(|seq| next_term(seq)).sum())
 }
 
 pub fn part_two(input: &str) -> Option<i32> {
-    Some(
-        input
-            .lines()
-            .map(|line| {
-                line.split_whitespace()
-                    .map(|n| n.parse::<i32>().unwrap())
-                    .collect_vec()
-            })
-            .map(|seq| prev_term(seq.as_slice()))
-            .sum(),
-    )
+    Some(parse_input(input).iter().map(|seq| prev_term(seq)).sum())
 }
 
 #[cfg(test)]
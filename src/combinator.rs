@@ -0,0 +1,298 @@
+/// A small parser-combinator toolkit for puzzle formats.
+/// A parser takes the remaining input and returns the parsed value together with the rest,
+/// so parsers compose into declarative descriptions of a line:
+///
+/// ```
+/// use advent_of_code::combinator::{identifier, literal, parse_all, seq, separated, ws};
+///
+/// let node = seq((identifier(), ws(literal("=")), ws(literal("(")), separated(ws(identifier()), literal(",")), literal(")")));
+/// let (name, _, _, next, _) = parse_all(node, "AAA = (BBB, CCC)").unwrap();
+/// assert_eq!((name, next), ("AAA", vec!["BBB", "CCC"]));
+/// ```
+///
+/// Errors are [`ParseError`]s pointing at the position where parsing failed.
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+
+/// The parsed value and the remaining input.
+pub type PResult<'a, T> = Result<(T, &'a str), ParseError>;
+
+pub trait Parser<'a, T> {
+    fn parse(&self, input: &'a str) -> PResult<'a, T>;
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(&'a str) -> PResult<'a, T>,
+{
+    fn parse(&self, input: &'a str) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Matches `expected` exactly.
+pub fn literal<'a>(expected: &'static str) -> impl Fn(&'a str) -> PResult<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(expected) {
+        Some(rest) => Ok((&input[..expected.len()], rest)),
+        None => Err(ParseError::new(input, format!("expected `{expected}`"))),
+    }
+}
+
+/// Parses an integer with an optional leading `-`.
+pub fn number<'a, T: FromStr>() -> impl Fn(&'a str) -> PResult<'a, T> {
+    move |input: &'a str| {
+        let sign = usize::from(input.starts_with('-'));
+        let digits = input[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len() - sign);
+
+        if digits == 0 {
+            return Err(ParseError::new(input, "expected a number"));
+        }
+
+        let (token, rest) = input.split_at(sign + digits);
+        match token.parse() {
+            Ok(value) => Ok((value, rest)),
+            Err(_) => Err(ParseError::new(
+                input,
+                format!("`{token}` is not a valid {}", std::any::type_name::<T>()),
+            )),
+        }
+    }
+}
+
+/// Parses a run of letters, digits and underscores, e.g. a node name like `11A`.
+pub fn identifier<'a>() -> impl Fn(&'a str) -> PResult<'a, &'a str> {
+    move |input: &'a str| {
+        let end = input
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(input.len());
+
+        if end == 0 {
+            return Err(ParseError::new(input, "expected an identifier"));
+        }
+
+        Ok(input.split_at(end))
+    }
+}
+
+/// Skips whitespace before running `parser`.
+pub fn ws<'a, T>(parser: impl Parser<'a, T>) -> impl Fn(&'a str) -> PResult<'a, T> {
+    move |input: &'a str| parser.parse(input.trim_start())
+}
+
+/// Transforms the value of `parser` with `f`.
+pub fn map<'a, T, U>(
+    parser: impl Parser<'a, T>,
+    f: impl Fn(T) -> U,
+) -> impl Fn(&'a str) -> PResult<'a, U> {
+    move |input: &'a str| {
+        let (value, rest) = parser.parse(input)?;
+        Ok((f(value), rest))
+    }
+}
+
+/// Runs `prefix`, then `parser`, keeping only the value of `parser`.
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+) -> impl Fn(&'a str) -> PResult<'a, T> {
+    move |input: &'a str| {
+        let (_, rest) = prefix.parse(input)?;
+        parser.parse(rest)
+    }
+}
+
+/// Runs `parser` between `open` and `close`, keeping only the value of `parser`.
+pub fn delimited<'a, O, T, C>(
+    open: impl Parser<'a, O>,
+    parser: impl Parser<'a, T>,
+    close: impl Parser<'a, C>,
+) -> impl Fn(&'a str) -> PResult<'a, T> {
+    move |input: &'a str| {
+        let (_, rest) = open.parse(input)?;
+        let (value, rest) = parser.parse(rest)?;
+        let (_, rest) = close.parse(rest)?;
+        Ok((value, rest))
+    }
+}
+
+/// Parses one or more `item`s separated by `separator`.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Fn(&'a str) -> PResult<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+
+        while let Ok((_, after_separator)) = separator.parse(rest) {
+            let (value, after_item) = item.parse(after_separator)?;
+            items.push(value);
+            rest = after_item;
+        }
+
+        Ok((items, rest))
+    }
+}
+
+/// Parses `item` one or more times.
+pub fn many<'a, T>(item: impl Parser<'a, T>) -> impl Fn(&'a str) -> PResult<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+
+        while let Ok((value, after_item)) = item.parse(rest) {
+            items.push(value);
+            rest = after_item;
+        }
+
+        Ok((items, rest))
+    }
+}
+
+/// A tuple of parsers that run one after another.
+pub trait Sequence<'a, T> {
+    fn parse_sequence(&self, input: &'a str) -> PResult<'a, T>;
+}
+
+/// A tuple of parsers with the same output, tried in order.
+pub trait Alternatives<'a, T> {
+    fn parse_alternatives(&self, input: &'a str) -> PResult<'a, T>;
+}
+
+/// Keeps the error that got furthest into the input. Errors at the same position are combined,
+/// e.g. into "expected `L` or `R`".
+fn furthest(a: ParseError, b: ParseError) -> ParseError {
+    if a.address != b.address {
+        return if a.address > b.address { a } else { b };
+    }
+
+    match b.message.strip_prefix("expected ") {
+        Some(expected) if a.message.starts_with("expected ") => ParseError {
+            message: format!("{} or {expected}", a.message),
+            ..a
+        },
+        _ => a,
+    }
+}
+
+macro_rules! impl_tuples {
+    ($first:tt $first_parser:ident $first_output:ident $first_value:ident
+        $(, $index:tt $parser:ident $output:ident $value:ident)*) => {
+        impl<'a, $first_output, $first_parser: Parser<'a, $first_output>, $($output, $parser: Parser<'a, $output>),*>
+            Sequence<'a, ($first_output, $($output,)*)> for ($first_parser, $($parser,)*)
+        {
+            fn parse_sequence(&self, input: &'a str) -> PResult<'a, ($first_output, $($output,)*)> {
+                let ($first_value, rest) = self.$first.parse(input)?;
+                $(let ($value, rest) = self.$index.parse(rest)?;)*
+                Ok((($first_value, $($value,)*), rest))
+            }
+        }
+
+        impl<'a, T, $first_parser: Parser<'a, T>, $($parser: Parser<'a, T>),*>
+            Alternatives<'a, T> for ($first_parser, $($parser,)*)
+        {
+            fn parse_alternatives(&self, input: &'a str) -> PResult<'a, T> {
+                let mut error = match self.$first.parse(input) {
+                    Ok(result) => return Ok(result),
+                    Err(err) => err,
+                };
+                $(
+                    match self.$index.parse(input) {
+                        Ok(result) => return Ok(result),
+                        Err(err) => error = furthest(error, err),
+                    }
+                )*
+                Err(error)
+            }
+        }
+    };
+}
+
+impl_tuples!(0 P0 O0 v0, 1 P1 O1 v1);
+impl_tuples!(0 P0 O0 v0, 1 P1 O1 v1, 2 P2 O2 v2);
+impl_tuples!(0 P0 O0 v0, 1 P1 O1 v1, 2 P2 O2 v2, 3 P3 O3 v3);
+impl_tuples!(0 P0 O0 v0, 1 P1 O1 v1, 2 P2 O2 v2, 3 P3 O3 v3, 4 P4 O4 v4);
+impl_tuples!(0 P0 O0 v0, 1 P1 O1 v1, 2 P2 O2 v2, 3 P3 O3 v3, 4 P4 O4 v4, 5 P5 O5 v5);
+
+/// Runs a tuple of parsers one after another, returning a tuple of their values.
+pub fn seq<'a, T>(parsers: impl Sequence<'a, T>) -> impl Fn(&'a str) -> PResult<'a, T> {
+    move |input: &'a str| parsers.parse_sequence(input)
+}
+
+/// Tries a tuple of parsers in order, returning the value of the first that succeeds.
+pub fn alt<'a, T>(parsers: impl Alternatives<'a, T>) -> impl Fn(&'a str) -> PResult<'a, T> {
+    move |input: &'a str| parsers.parse_alternatives(input)
+}
+
+/// Runs `parser` on the whole `input`, ignoring surrounding whitespace. Errors are located in `input`.
+pub fn parse_all<'a, T>(parser: impl Parser<'a, T>, input: &'a str) -> Result<T, ParseError> {
+    let result = parser.parse(input.trim_start()).and_then(|(value, rest)| {
+        let rest = rest.trim();
+        if rest.is_empty() {
+            Ok(value)
+        } else {
+            Err(ParseError::new(rest, format!("unexpected `{rest}`")))
+        }
+    });
+
+    result.map_err(|err| err.locate(input))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        alt, delimited, identifier, literal, many, map, number, parse_all, preceded, separated,
+        seq, ws,
+    };
+
+    #[test]
+    fn parses_primitives() {
+        assert_eq!(literal("Game")("Game 1"), Ok(("Game", " 1")));
+        assert_eq!(number::<i32>()("-12, 3"), Ok((-12, ", 3")));
+        assert_eq!(identifier()("11A = (11B"), Ok(("11A", " = (11B")));
+        assert_eq!(ws(number::<u8>())("  7"), Ok((7, "")));
+        assert!(number::<u8>()("-").is_err());
+        assert!(identifier()(" a").is_err());
+    }
+
+    #[test]
+    fn combines_parsers() {
+        let game = preceded(literal("Game "), number::<u32>());
+        assert_eq!(parse_all(game, "Game 12"), Ok(12));
+
+        let list = delimited(
+            literal("("),
+            separated(ws(identifier()), literal(",")),
+            literal(")"),
+        );
+        assert_eq!(parse_all(list, "(BBB, CCC)"), Ok(vec!["BBB", "CCC"]));
+
+        let colour = seq((
+            ws(number::<u32>()),
+            ws(alt((literal("red"), literal("blue")))),
+        ));
+        assert_eq!(parse_all(&colour, "3 blue"), Ok((3, "blue")));
+
+        assert_eq!(parse_all(many(literal("ab")), "abab"), Ok(vec!["ab", "ab"]));
+
+        let doubled = map(number::<u32>(), |n| n * 2);
+        assert_eq!(parse_all(doubled, "21"), Ok(42));
+    }
+
+    #[test]
+    fn reports_error_positions() {
+        let direction = alt((literal("L"), literal("R")));
+        let err = parse_all(separated(&direction, literal(",")), "L,R,X").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+        assert_eq!(err.message, "expected `L` or `R`");
+
+        let err = parse_all(number::<u8>(), "256").unwrap_err();
+        assert_eq!(err.message, "`256` is not a valid u8");
+
+        let err = parse_all(identifier(), "AAA BBB").unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (5, "unexpected `BBB`"));
+    }
+}
@@ -0,0 +1,191 @@
+/// Counts how often each item occurs, like a multiset.
+/// Items that are not in the counter have a count of 0, and counts never go below 0.
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Debug, Clone)]
+pub struct Counter<T> {
+    counts: HashMap<T, usize>,
+}
+
+impl<T> Default for Counter<T> {
+    fn default() -> Self {
+        Self { counts: HashMap::new() }
+    }
+}
+
+impl<T: Eq + Hash> PartialEq for Counter<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.counts == other.counts
+    }
+}
+
+impl<T: Eq + Hash> Eq for Counter<T> {}
+
+impl<T: Eq + Hash> Counter<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, item: T) {
+        self.add_n(item, 1);
+    }
+
+    pub fn add_n(&mut self, item: T, n: usize) {
+        if n > 0 {
+            *self.counts.entry(item).or_insert(0) += n;
+        }
+    }
+
+    /// Decreases the count of `item` by `n`, removing it once the count reaches 0.
+    pub fn remove_n(&mut self, item: &T, n: usize) {
+        if let Some(count) = self.counts.get_mut(item) {
+            *count = count.saturating_sub(n);
+            if *count == 0 {
+                self.counts.remove(item);
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, item: &T) -> usize {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    #[must_use]
+    pub fn contains(&self, item: &T) -> bool {
+        self.counts.contains_key(item)
+    }
+
+    /// The number of distinct items.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The sum of all counts.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// All items with their counts, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.counts.iter().map(|(item, &count)| (item, count))
+    }
+
+    /// The counts sorted from high to low, without the items. E.g. `[3, 2]` for a full house.
+    #[must_use]
+    pub fn signature(&self) -> Vec<usize> {
+        let mut counts: Vec<usize> = self.counts.values().copied().collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts
+    }
+}
+
+impl<T: Clone + Eq + Hash> Counter<T> {
+    /// The items in both counters, with the smaller count.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.iter()
+            .map(|(item, count)| (item.clone(), count.min(other.get(item))))
+            .collect()
+    }
+
+    /// The items in either counter, with the larger count.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for (item, count) in other.iter() {
+            let extra = count.saturating_sub(self.get(item));
+            union.add_n(item.clone(), extra);
+        }
+        union
+    }
+
+    /// The counts of this counter minus those of `other`, dropping items that reach 0.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        self.iter()
+            .map(|(item, count)| (item.clone(), count.saturating_sub(other.get(item))))
+            .collect()
+    }
+}
+
+impl<T: Eq + Hash + Ord> Counter<T> {
+    /// The `n` most common items with their counts. Items with the same count are ordered by item.
+    #[must_use]
+    pub fn most_common(&self, n: usize) -> Vec<(&T, usize)> {
+        let mut items: Vec<(&T, usize)> = self.iter().collect();
+        items.sort_unstable_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        items.truncate(n);
+        items
+    }
+}
+
+impl<T: Eq + Hash> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<(T, usize)> for Counter<T> {
+    /// Collects items with their counts, adding up repeated items.
+    fn from_iter<I: IntoIterator<Item = (T, usize)>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        for (item, n) in iter {
+            counter.add_n(item, n);
+        }
+        counter
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Counter;
+
+    #[test]
+    fn counts_items() {
+        let mut counter: Counter<char> = "abracadabra".chars().collect();
+        assert_eq!(counter.get(&'a'), 5);
+        assert_eq!(counter.get(&'z'), 0);
+        assert_eq!((counter.len(), counter.total()), (5, 11));
+        assert_eq!(counter.signature(), vec![5, 2, 2, 1, 1]);
+        assert_eq!(
+            counter.most_common(3),
+            vec![(&'a', 5), (&'b', 2), (&'r', 2)]
+        );
+
+        counter.extend("zz".chars());
+        counter.remove_n(&'a', 10);
+        assert!(!counter.contains(&'a'));
+        assert_eq!(counter.most_common(1), vec![(&'b', 2)]);
+    }
+
+    #[test]
+    fn combines_counters() {
+        let a: Counter<u32> = [1, 1, 1, 2, 3].into_iter().collect();
+        let b: Counter<u32> = [1, 2, 2, 4].into_iter().collect();
+
+        let expected = |items: &[(u32, usize)]| items.iter().copied().collect::<Counter<_>>();
+        assert_eq!(a.intersection(&b), expected(&[(1, 1), (2, 1)]));
+        assert_eq!(a.union(&b), expected(&[(1, 3), (2, 2), (3, 1), (4, 1)]));
+        assert_eq!(a.difference(&b), expected(&[(1, 2), (3, 1)]));
+        assert!(a.difference(&a).is_empty());
+    }
+}
@@ -0,0 +1,264 @@
+/// Cycle detection for sequences of states, where each state determines the next one.
+/// Such a sequence over finitely many states always ends up in a cycle: after `start` steps it
+/// reaches a state it will see again every `length` steps.
+/// [`floyd`] and [`brent`] need constant memory, the hash map based functions remember every
+/// state, but also find goal states and extrapolate the sequence to any step.
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of steps before the cycle, i.e. the first step whose state repeats.
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The step before `start + length` that has the same state as `step`.
+    #[must_use]
+    pub fn reduce(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+}
+
+/// Floyd's tortoise and hare algorithm.
+pub fn floyd<S: Clone + Eq>(initial: S, mut next: impl FnMut(&S) -> S) -> Cycle {
+    // the hare moves twice as fast, they meet at a multiple of the cycle length.
+    let mut tortoise = next(&initial);
+    let mut hare = next(&tortoise);
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        let ahead = next(&hare);
+        hare = next(&ahead);
+    }
+
+    // a multiple of the length apart, they meet where the cycle starts.
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = next(&tortoise);
+    while tortoise != hare {
+        hare = next(&hare);
+        length += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Brent's algorithm, which usually needs fewer steps than [`floyd`].
+pub fn brent<S: Clone + Eq>(initial: S, mut next: impl FnMut(&S) -> S) -> Cycle {
+    // search successive powers of two for the cycle length.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = next(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = next(&hare);
+        length += 1;
+    }
+
+    // with the hare `length` steps ahead, they meet where the cycle starts.
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..length {
+        hare = next(&hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Finds the cycle by remembering every state.
+/// Also returns the states of all steps before `start + length`.
+pub fn find<S: Clone + Eq + Hash>(initial: S, mut next: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let length = states.len() - start;
+            return (Cycle { start, length }, states);
+        }
+
+        seen.insert(state.clone(), states.len());
+        let following = next(&state);
+        states.push(state);
+        state = following;
+    }
+}
+
+/// The cycle of a sequence and the steps at which it reaches a goal state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Goals {
+    pub cycle: Cycle,
+    /// The goal steps before `start + length`, in ascending order.
+    /// Steps from `start` on repeat every `length` steps.
+    pub positions: Vec<usize>,
+}
+
+impl Goals {
+    #[must_use]
+    pub fn is_goal(&self, step: usize) -> bool {
+        self.positions
+            .binary_search(&self.cycle.reduce(step))
+            .is_ok()
+    }
+
+    /// The first step that reaches a goal, if the sequence reaches one at all.
+    #[must_use]
+    pub fn first(&self) -> Option<usize> {
+        self.positions.first().copied()
+    }
+
+    /// The goal steps inside the cycle, which repeat every `length` steps.
+    pub fn in_cycle(&self) -> impl Iterator<Item = usize> + '_ {
+        self.positions
+            .iter()
+            .copied()
+            .filter(|&step| step >= self.cycle.start)
+    }
+
+    /// All steps that reach a goal, in ascending order. Infinite if the cycle contains a goal.
+    pub fn steps(&self) -> impl Iterator<Item = usize> + '_ {
+        let before = self
+            .positions
+            .iter()
+            .copied()
+            .take_while(|&step| step < self.cycle.start);
+
+        let repeated = (0..)
+            .take_while(|_| self.in_cycle().next().is_some())
+            .flat_map(move |round| {
+                self.in_cycle()
+                    .map(move |step| step + round * self.cycle.length)
+            });
+
+        before.chain(repeated)
+    }
+}
+
+/// Finds the cycle of a sequence and the steps at which `is_goal` holds.
+pub fn goals<S: Clone + Eq + Hash>(
+    initial: S,
+    next: impl FnMut(&S) -> S,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Goals {
+    let (cycle, states) = find(initial, next);
+    let positions = states
+        .iter()
+        .enumerate()
+        .filter(|(_, state)| is_goal(state))
+        .map(|(step, _)| step)
+        .collect();
+
+    Goals { cycle, positions }
+}
+
+/// The state after `steps` steps, skipping over repetitions of the cycle.
+pub fn extrapolate<S: Clone + Eq + Hash>(
+    initial: S,
+    mut next: impl FnMut(&S) -> S,
+    steps: usize,
+) -> S {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+
+    while states.len() < steps {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                length: states.len() - start,
+            };
+            return states.swap_remove(cycle.reduce(steps));
+        }
+
+        seen.insert(state.clone(), states.len());
+        let following = next(&state);
+        states.push(state);
+        state = following;
+    }
+
+    state
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{brent, extrapolate, find, floyd, goals, Cycle};
+
+    /// 0, 1, 2, then 3 to 8 over and over.
+    fn next(&x: &u32) -> u32 {
+        if x == 8 {
+            3
+        } else {
+            x + 1
+        }
+    }
+
+    #[test]
+    fn detects_cycles() {
+        let expected = Cycle { start: 3, length: 6 };
+        assert_eq!(floyd(0, next), expected);
+        assert_eq!(brent(0, next), expected);
+
+        let (cycle, states) = find(0, next);
+        assert_eq!(cycle, expected);
+        assert_eq!(states, (0..=8).collect::<Vec<_>>());
+
+        // a sequence that starts in its cycle.
+        let pure = Cycle { start: 0, length: 6 };
+        assert_eq!(floyd(3, next), pure);
+        assert_eq!(brent(3, next), pure);
+        assert_eq!(find(3, next).0, pure);
+        assert_eq!(brent(7, |&x| x), Cycle { start: 0, length: 1 });
+    }
+
+    #[test]
+    fn finds_goals() {
+        let multiples = goals(0, next, |&x| x % 4 == 0);
+        assert_eq!(multiples.positions, vec![0, 4, 8]);
+        assert_eq!(multiples.first(), Some(0));
+        assert_eq!(multiples.in_cycle().collect::<Vec<_>>(), vec![4, 8]);
+        assert_eq!(
+            multiples.steps().take(5).collect::<Vec<_>>(),
+            vec![0, 4, 8, 10, 14]
+        );
+        assert!(multiples.is_goal(10));
+        assert!(!multiples.is_goal(11));
+
+        let unreachable = goals(0, next, |&x| x == 9);
+        assert_eq!(unreachable.first(), None);
+        assert_eq!(unreachable.steps().next(), None);
+
+        let prefix_only = goals(0, next, |&x| x == 1);
+        assert_eq!(prefix_only.steps().collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn extrapolates_sequences() {
+        assert_eq!(extrapolate(0, next, 2), 2);
+        assert_eq!(extrapolate(0, next, 9), 3);
+        assert_eq!(extrapolate(0, next, 1_000_000_000), 4);
+    }
+}
@@ -0,0 +1,414 @@
+/// A two-dimensional grid, stored row by row.
+/// Positions are `(row, column)` tuples, with `(0, 0)` in the top left corner.
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+pub type Position = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    Empty,
+    /// A line has a different width than the first line. Lines are 1-indexed.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Empty => write!(f, "grid is empty"),
+            Error::Ragged { line, expected, found } => write!(
+                f,
+                "line {line} has {found} columns, expected {expected} like the first line"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// Parses a character map, e.g. a puzzle input. Lines must have the same width.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        Self::parse_with(s, |c| c)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map, converting each character with `f`.
+    pub fn parse_with(s: &str, f: impl Fn(char) -> T) -> Result<Self, Error> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (i, line) in s.lines().enumerate() {
+            let len = line.chars().count();
+            let expected = *width.get_or_insert(len);
+            if len != expected {
+                return Err(Error::Ragged {
+                    line: i + 1,
+                    expected,
+                    found: len,
+                });
+            }
+
+            cells.extend(line.chars().map(&f));
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self { width, height, cells }),
+            _ => Err(Error::Empty),
+        }
+    }
+
+    /// Creates a grid from cells in row-major order.
+    /// Returns [`None`] if the grid would be empty or the cell count does not match.
+    #[must_use]
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        (width > 0 && height > 0 && cells.len() == width * height).then_some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub fn contains(&self, (row, column): Position) -> bool {
+        row < self.height && column < self.width
+    }
+
+    #[must_use]
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.0 * self.width + position.1])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.0 * self.width + position.1])
+        } else {
+            None
+        }
+    }
+
+    /// Moves `position` by `offset`, if the result is inside the grid.
+    #[must_use]
+    pub fn offset(&self, (row, column): Position, (dr, dc): (isize, isize)) -> Option<Position> {
+        let position = (row.checked_add_signed(dr)?, column.checked_add_signed(dc)?);
+        self.contains(position).then_some(position)
+    }
+
+    /// The up to 4 orthogonal neighbours of `position`, clockwise starting at the top.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        OFFSETS_4
+            .into_iter()
+            .filter_map(move |offset| self.offset(position, offset))
+    }
+
+    /// The up to 8 orthogonal and diagonal neighbours of `position`, clockwise starting at the top left.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        OFFSETS_8
+            .into_iter()
+            .filter_map(move |offset| self.offset(position, offset))
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// All cells with their positions in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    #[must_use]
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    #[must_use]
+    pub fn column(&self, column: usize) -> Option<impl Iterator<Item = &T>> {
+        (column < self.width).then(|| self.cells.iter().skip(column).step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.cells.iter().skip(column).step_by(self.width))
+    }
+
+    /// Walks from `start` in the direction of `step` until leaving the grid, including `start`.
+    pub fn ray(
+        &self,
+        start: Position,
+        step: (isize, isize),
+    ) -> impl Iterator<Item = Position> + '_ {
+        std::iter::successors(self.contains(start).then_some(start), move |&p| {
+            self.offset(p, step)
+        })
+    }
+
+    /// Diagonals running from top left to bottom right, starting at the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = Vec<&T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|row| (row, 0))
+            .chain((1..self.width).map(|column| (0, column)));
+
+        starts.map(|start| self.ray(start, (1, 1)).map(|p| &self[p]).collect())
+    }
+
+    /// Diagonals running from top right to bottom left, starting at the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Vec<&T>> {
+        let starts = (0..self.width)
+            .map(|column| (0, column))
+            .chain((1..self.height).map(|row| (row, self.width - 1)));
+
+        starts.map(|start| self.ray(start, (1, -1)).map(|p| &self[p]).collect())
+    }
+
+    /// Positions of all cells matching `predicate`, in row-major order.
+    pub fn find_all(&self, predicate: impl Fn(&T) -> bool) -> Vec<Position> {
+        self.iter()
+            .filter(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    /// Position of the first cell matching `predicate`, in row-major order.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    #[must_use]
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is 0, grids are never empty.
+    #[must_use]
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        assert!(width > 0 && height > 0, "grid is empty");
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        self.rebuild(self.height, self.width, |(row, column)| (column, row))
+    }
+
+    #[must_use]
+    pub fn rotate_clockwise(&self) -> Self {
+        self.rebuild(self.height, self.width, |(row, column)| {
+            (self.height - 1 - column, row)
+        })
+    }
+
+    #[must_use]
+    pub fn rotate_counterclockwise(&self) -> Self {
+        self.rebuild(self.height, self.width, |(row, column)| {
+            (column, self.width - 1 - row)
+        })
+    }
+
+    /// Builds a grid of the given size, taking each cell from `source(position)` in this grid.
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(Position) -> Position) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |column| (row, column)))
+            .map(|p| self[source(p)].clone())
+            .collect();
+
+        Self { width, height, cells }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).expect("position out of bounds")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position).expect("position out of bounds")
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Error, Grid};
+
+    fn get_grid() -> Grid<char> {
+        Grid::parse("abc\ndef").unwrap()
+    }
+
+    #[test]
+    fn parses_character_maps() {
+        let grid = get_grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let digits = Grid::parse_with("12\n34", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(digits[(1, 0)], 3);
+    }
+
+    #[test]
+    fn rejects_invalid_maps() {
+        assert_eq!(Grid::parse(""), Err(Error::Empty));
+        assert_eq!(
+            Grid::parse("abc\nde\nfgh"),
+            Err(Error::Ragged { line: 2, expected: 3, found: 2 })
+        );
+
+        assert_eq!(Grid::from_cells(0, 2, Vec::<char>::new()), None);
+        assert_eq!(Grid::from_cells(2, 0, Vec::<char>::new()), None);
+        assert_eq!(Grid::from_cells(2, 2, vec!['a'; 3]), None);
+        assert_eq!(
+            Grid::from_cells(2, 1, vec!['a'; 2]),
+            Some(Grid::filled(2, 1, 'a'))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "grid is empty")]
+    fn rejects_empty_filled_grids() {
+        let _ = Grid::filled(0, 3, '.');
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid = get_grid();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours4((1, 1)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours8((0, 1)).collect::<Vec<_>>(),
+            vec![(0, 2), (1, 2), (1, 1), (1, 0), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn iterates_lines() {
+        let grid = get_grid();
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(2).unwrap().collect::<String>(), "cf");
+        assert!(grid.column(3).is_none());
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(
+            grid.diagonals()
+                .map(|d| d.into_iter().collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            grid.anti_diagonals()
+                .map(|d| d.into_iter().collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["a", "bd", "ce", "f"]
+        );
+    }
+
+    #[test]
+    fn transforms_grids() {
+        let grid = get_grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+
+    #[test]
+    fn finds_cells() {
+        let grid = Grid::parse("#.#\n..#").unwrap();
+        assert_eq!(grid.find_all(|&c| c == '#'), vec![(0, 0), (0, 2), (1, 2)]);
+        assert_eq!(grid.find(|&c| c == '.'), Some((0, 1)));
+        assert_eq!(grid.find(|&c| c == 'x'), None);
+    }
+}
@@ -0,0 +1,232 @@
+/// Sets of half-open ranges, for puzzles that move large ranges of values around.
+/// A [`RangeSet`] is kept normalised: its ranges are sorted, non-empty and neither overlap nor
+/// touch, so set operations and mappings run in the number of ranges instead of values.
+use std::{
+    iter::Sum,
+    ops::{Add, Range, Sub},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: Copy + Ord> RangeSet<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The normalised ranges, sorted by their start.
+    #[must_use]
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(index).is_some_and(|r| r.contains(&value))
+    }
+
+    /// The smallest value in the set.
+    #[must_use]
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    /// Adds `range`, merging it with any ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let start = self.ranges.partition_point(|r| r.end < range.start);
+        let end = self.ranges.partition_point(|r| r.start <= range.end);
+
+        let merged = if start < end {
+            self.ranges[start].start.min(range.start)..self.ranges[end - 1].end.max(range.end)
+        } else {
+            range
+        };
+
+        self.ranges.splice(start..end, [merged]);
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges
+            .iter()
+            .chain(other.ranges.iter())
+            .cloned()
+            .collect()
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        // overlaps of normalised sets are sorted and disjoint already.
+        Self { ranges }
+    }
+
+    /// The values in this set that are not in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+
+        for range in &self.ranges {
+            let mut start = range.start;
+
+            // skip ranges of `other` that end before this range.
+            while other.ranges.get(j).is_some_and(|r| r.end <= start) {
+                j += 1;
+            }
+
+            let mut k = j;
+            while let Some(cut) = other.ranges.get(k).filter(|r| r.start < range.end) {
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+                k += 1;
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        Self { ranges }
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> RangeSet<T> {
+    /// Maps the set through a piecewise-linear map, splitting ranges where needed.
+    /// Each piece moves the values in `source` so that `source.start` lands on `destination_start`.
+    /// Values outside all sources keep their value. If sources overlap, the first piece wins.
+    #[must_use]
+    pub fn map_pieces(&self, pieces: impl IntoIterator<Item = (Range<T>, T)>) -> Self {
+        let mut unmapped = self.clone();
+        let mut mapped = Self::new();
+
+        for (source, destination_start) in pieces {
+            let piece = Self::from(source.clone());
+            for range in unmapped.intersection(&piece).ranges {
+                let start = range.start - source.start + destination_start;
+                mapped.insert(start..start + (range.end - range.start));
+            }
+            unmapped = unmapped.difference(&piece);
+        }
+
+        mapped.union(&unmapped)
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Sum> RangeSet<T> {
+    /// The number of values in the set.
+    #[must_use]
+    pub fn count(&self) -> T {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut ranges: Vec<Range<T>> = iter.into_iter().filter(|r| !r.is_empty()).collect();
+        ranges.sort_unstable_by_key(|r| r.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+
+        Self { ranges: merged }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::RangeSet;
+
+    fn set(ranges: &[(i64, i64)]) -> RangeSet<i64> {
+        ranges.iter().map(|&(start, end)| start..end).collect()
+    }
+
+    #[test]
+    fn normalises_ranges() {
+        let ranges = set(&[(5, 8), (1, 3), (3, 4), (7, 10), (12, 12)]);
+        assert_eq!(ranges.ranges(), [1..4, 5..10]);
+        assert_eq!(ranges.count(), 8);
+        assert_eq!(ranges.min(), Some(1));
+        assert!(ranges.contains(3));
+        assert!(!ranges.contains(4));
+        assert!(!ranges.contains(10));
+
+        let mut inserted = set(&[(1, 3), (6, 8), (10, 12)]);
+        inserted.insert(3..6);
+        assert_eq!(inserted.ranges(), [1..8, 10..12]);
+        inserted.insert(-5..-4);
+        inserted.insert(9..9);
+        assert_eq!(inserted.ranges(), [-5..-4, 1..8, 10..12]);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 12), (14, 20)]);
+        assert_eq!(a.union(&b).ranges(), [0..20]);
+        assert_eq!(a.intersection(&b).ranges(), [3..5, 10..12, 14..15]);
+        assert_eq!(a.difference(&b).ranges(), [0..3, 12..14]);
+        assert_eq!(b.difference(&a).ranges(), [5..10, 15..20]);
+        assert!(a.difference(&a).is_empty());
+        assert_eq!(a.difference(&RangeSet::new()), a);
+    }
+
+    #[test]
+    fn maps_through_pieces() {
+        // seeds 79..93 and 55..68 through the seed-to-soil map of day 5.
+        let seeds = set(&[(79, 93), (55, 68)]);
+        let soil = seeds.map_pieces([(98..100, 50), (50..98, 52)]);
+        assert_eq!(soil.ranges(), [57..70, 81..95]);
+
+        // values outside all pieces keep their value, ranges are split at piece boundaries.
+        let split = set(&[(0, 10)]).map_pieces([(2..4, 100), (8..20, 0)]);
+        assert_eq!(split.ranges(), [0..2, 4..8, 100..102]);
+    }
+}
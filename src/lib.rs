@@ -1,4 +1,14 @@
+pub mod combinator;
+pub mod counter;
+pub mod cycles;
 mod day;
+pub mod grid;
+pub mod intervals;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod point;
+pub mod search;
 pub mod template;
 
 pub use day::*;
@@ -1,20 +1,29 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, auth, download, leaderboard, read, scaffold, solve, stars, start, status, test, watch,
+};
 use args::{parse, AppArguments};
 
 mod args {
     use std::process;
 
+    use advent_of_code::template::commands::{auth, test};
+    use advent_of_code::template::readme_benchmarks::{parse_columns, TableOptions};
     use advent_of_code::Day;
 
     pub enum AppArguments {
         Download {
             day: Day,
+            wait: bool,
+            force: bool,
         },
         Read {
             day: Day,
+            part: Option<u8>,
         },
         Scaffold {
             day: Day,
+            force: bool,
+            dry_run: bool,
         },
         Solve {
             day: Day,
@@ -25,6 +34,33 @@ mod args {
         All {
             release: bool,
             time: bool,
+            table: TableOptions,
+            chart: Option<String>,
+            csv: Option<String>,
+        },
+        Start {
+            day: Day,
+            wait: bool,
+        },
+        Auth {
+            action: auth::Action,
+            token: Option<String>,
+            expires: Option<String>,
+        },
+        Leaderboard {
+            id: Option<String>,
+            file: Option<String>,
+            day: Option<Day>,
+        },
+        Stars {
+            sync: bool,
+        },
+        Status,
+        Test {
+            days: Option<Vec<Day>>,
+        },
+        WatchDay {
+            day: Day,
         },
     }
 
@@ -32,19 +68,62 @@ mod args {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
+            Some("all") => {
+                let defaults = TableOptions::default();
+                AppArguments::All {
+                    release: args.contains("--release"),
+                    time: args.contains("--time"),
+                    table: TableOptions {
+                        columns: args
+                            .opt_value_from_fn("--columns", parse_columns)?
+                            .unwrap_or(defaults.columns),
+                        sort: args.opt_value_from_str("--sort")?.unwrap_or(defaults.sort),
+                        path: args.opt_value_from_str("--table")?.unwrap_or(defaults.path),
+                    },
+                    chart: args.opt_value_from_str("--chart")?,
+                    csv: args.opt_value_from_str("--csv")?,
+                }
+            }
+            Some("auth") => AppArguments::Auth {
+                action: args.free_from_str()?,
+                expires: args.opt_value_from_str("--expires")?,
+                token: args.opt_free_from_str()?,
+            },
+            Some("download") => AppArguments::Download {
+                day: args.free_from_str()?,
+                wait: args.contains("--wait"),
+                force: args.contains("--force"),
+            },
+            Some("leaderboard") => AppArguments::Leaderboard {
+                file: args.opt_value_from_str("--file")?,
+                day: args.opt_value_from_str("--day")?,
+                id: args.opt_free_from_str()?,
+            },
+            Some("read") => AppArguments::Read {
+                day: args.free_from_str()?,
+                part: args.opt_value_from_str("--part")?,
+            },
+            Some("scaffold") => AppArguments::Scaffold {
+                day: args.free_from_str()?,
+                force: args.contains("--force"),
+                dry_run: args.contains("--dry-run"),
             },
-            Some("download") => AppArguments::Download { day: args.free_from_str()? },
-            Some("read") => AppArguments::Read { day: args.free_from_str()? },
-            Some("scaffold") => AppArguments::Scaffold { day: args.free_from_str()? },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 time: args.contains("--time"),
             },
+            Some("stars") => AppArguments::Stars { sync: args.contains("--sync") },
+            Some("start") => AppArguments::Start {
+                day: args.free_from_str()?,
+                wait: args.contains("--wait"),
+            },
+            Some("status") => AppArguments::Status,
+            Some("test") => AppArguments::Test {
+                days: args.opt_free_from_fn(test::parse_days)?,
+            },
+            Some("watch-day") => AppArguments::WatchDay { day: args.free_from_str()? },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -71,13 +150,26 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All {
+                release,
+                time,
+                table,
+                chart,
+                csv,
+            } => all::handle(release, time, &table, chart.as_deref(), csv.as_deref()),
+            AppArguments::Auth { action, token, expires } => auth::handle(action, token, expires),
+            AppArguments::Download { day, wait, force } => download::handle(day, wait, force),
+            AppArguments::Leaderboard { id, file, day } => leaderboard::handle(id, file, day),
+            AppArguments::Read { day, part } => read::handle(day, part),
+            AppArguments::Scaffold { day, force, dry_run } => scaffold::handle(day, force, dry_run),
             AppArguments::Solve { day, release, time, submit } => {
                 solve::handle(day, release, time, submit)
             }
+            AppArguments::Stars { sync } => stars::handle(sync),
+            AppArguments::Start { day, wait } => start::handle(day, wait),
+            AppArguments::Status => status::handle(),
+            AppArguments::Test { days } => test::handle(days),
+            AppArguments::WatchDay { day } => watch::handle(day),
         },
     };
 }
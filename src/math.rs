@@ -0,0 +1,251 @@
+/// Number theory helpers: gcd and lcm, modular arithmetic and the Chinese remainder theorem.
+/// `gcd` and `lcm` work for all primitive integers, the modular functions use `i64`/`u64`
+/// and compute intermediate values with 128 bits, so they do not overflow.
+use std::ops::{Div, Rem, Sub};
+
+/// The primitive integer types.
+pub trait Integer:
+    Copy + Ord + Sub<Output = Self> + Div<Output = Self> + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// The absolute value, or [`None`] for the minimum of a signed type.
+    fn checked_abs(self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),+; $abs:expr) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn checked_abs(self) -> Option<Self> {
+                    $abs(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize; Some);
+impl_integer!(i8, i16, i32, i64, i128, isize; |n: Self| n.checked_abs());
+
+/// The greatest common divisor, which is never negative. `gcd(0, 0)` is 0.
+///
+/// # Panics
+///
+/// Panics if the result does not fit into `T`, which only happens for `gcd(MIN, 0)` and
+/// `gcd(MIN, MIN)` of signed types.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    // `MIN` has no absolute value, but its remainder modulo the other value does.
+    let (mut a, mut b) = match (a.checked_abs(), b.checked_abs()) {
+        (Some(a), Some(b)) => (a, b),
+        (None, Some(b)) if b != T::ZERO => (b, abs_remainder(a, b)),
+        (Some(a), None) if a != T::ZERO => (a, abs_remainder(b, a)),
+        _ => panic!("gcd does not fit into the type"),
+    };
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// `|n % modulus|` for a positive `modulus`, which is smaller than `modulus` and always fits.
+fn abs_remainder<T: Integer>(n: T, modulus: T) -> T {
+    let remainder = n % modulus;
+    if remainder < T::ZERO {
+        T::ZERO - remainder
+    } else {
+        remainder
+    }
+}
+
+/// The least common multiple, or [`None`] if it does not fit into `T`.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    // the lcm is at least as large as both absolute values.
+    let (abs_a, abs_b) = (a.checked_abs()?, b.checked_abs()?);
+    (abs_a / gcd(a, b)).checked_mul(abs_b)
+}
+
+/// The greatest common divisor of all `values`, 0 if there are none.
+/// Slices can be passed as `values.iter().copied()`.
+pub fn gcd_all<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::ZERO, gcd)
+}
+
+/// The least common multiple of all `values`, 1 if there are none.
+/// Returns [`None`] if it does not fit into `T`.
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::ONE, |acc, value| lcm(acc, value))
+}
+
+fn extended_gcd_128(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    let (mut old_t, mut t) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+        (old_t, t) = (t, old_t - quotient * t);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+/// Extended Euclidean algorithm. Returns `(g, x, y)` with `a * x + b * y = g = gcd(a, b)`.
+#[must_use]
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = extended_gcd_128(a.into(), b.into());
+    // the coefficients are bounded by the inputs, only `gcd(i64::MIN, 0)` does not fit.
+    let narrow = |n: i128| i64::try_from(n).expect("coefficient out of range");
+    (narrow(g), narrow(x), narrow(y))
+}
+
+fn mod_inv_128(a: i128, modulus: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd_128(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// The inverse of `a` modulo `modulus`, if `a` and `modulus` are coprime. `modulus` must be positive.
+#[must_use]
+pub fn mod_inv(a: i64, modulus: i64) -> Option<i64> {
+    assert!(modulus > 0, "modulus must be positive");
+    mod_inv_128(a.into(), modulus.into()).and_then(|x| i64::try_from(x).ok())
+}
+
+/// Computes `base^exp mod modulus` by repeated squaring. `modulus` must be positive.
+#[must_use]
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "modulus must be positive");
+    let modulus = u128::from(modulus);
+    let mut base = u128::from(base) % modulus;
+    let mut result = 1 % modulus;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+
+    // the result is smaller than the modulus, which came from an `u64`.
+    u64::try_from(result).unwrap_or(u64::MAX)
+}
+
+/// Combines `x ≡ a (mod m)` with `x ≡ residue (mod other)` into a single congruence.
+fn merge_congruences((a, m): (i128, i128), (residue, other): (i64, i64)) -> Option<(i128, i128)> {
+    assert!(other > 0, "moduli must be positive");
+    let (residue, other) = (i128::from(residue), i128::from(other));
+
+    let g = gcd(m, other);
+    let difference = residue - a;
+    if difference % g != 0 {
+        return None;
+    }
+
+    // solves a + m * k ≡ residue (mod other) for k, which is unique modulo other / g.
+    let reduced = other / g;
+    let k = (difference / g).rem_euclid(reduced) * mod_inv_128(m / g, reduced)? % reduced;
+    let combined = m / g * other;
+    if combined > i128::from(i64::MAX) {
+        return None;
+    }
+
+    Some(((a + m * k).rem_euclid(combined), combined))
+}
+
+/// Solves the system `x ≡ residue (mod modulus)` for all `(residue, modulus)` pairs with the
+/// Chinese remainder theorem. The moduli must be positive, but do not need to be coprime.
+/// Returns the smallest non-negative solution `x` and the combined modulus, i.e. the lcm of all
+/// moduli, so every `x + k * modulus` is a solution as well.
+/// Returns [`None`] if the congruences contradict each other or the modulus does not fit into `i64`.
+#[must_use]
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (x, modulus) = congruences.iter().try_fold((0, 1), |acc, &congruence| {
+        merge_congruences(acc, congruence)
+    })?;
+
+    Some((i64::try_from(x).ok()?, i64::try_from(modulus).ok()?))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{crt, extended_gcd, gcd, gcd_all, lcm, lcm_all, mod_inv, mod_pow};
+
+    #[test]
+    fn computes_gcd_and_lcm() {
+        assert_eq!(gcd(12_u32, 18), 6);
+        assert_eq!(gcd(-12_i64, 18), 6);
+        assert_eq!(gcd(0_u8, 0), 0);
+        assert_eq!(lcm(4_u64, 6), Some(12));
+        assert_eq!(lcm(-4_i32, 6), Some(12));
+        assert_eq!(lcm(0_u32, 6), Some(0));
+        assert_eq!(lcm(200_u8, 3), None);
+
+        assert_eq!(gcd_all([12_u64, 18, 27]), 3);
+        assert_eq!(lcm_all([2_u64, 3, 4].iter().copied()), Some(12));
+        assert_eq!(lcm_all(Vec::<u64>::new()), Some(1));
+        assert_eq!(lcm_all([u64::MAX, 2]), None);
+
+        assert_eq!(gcd(i64::MIN, 6), 2);
+        assert_eq!(gcd(-1, i32::MIN), 1);
+        assert_eq!(gcd(i8::MIN, -64), 64);
+        assert_eq!(lcm(i32::MIN, 1), None);
+        assert_eq!(lcm(3, i64::MIN), None);
+        assert_eq!(lcm(i8::MIN, 0), Some(0));
+        assert_eq!(lcm_all([2_i16, i16::MIN]), None);
+    }
+
+    #[test]
+    fn computes_modular_arithmetic() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(-3, 11), Some(7));
+        assert_eq!(mod_inv(6, 9), None);
+
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(u64::MAX, 2, u64::MAX - 1), 1);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // non-coprime moduli
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(0, i64::MAX), (0, i64::MAX - 1)]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "gcd does not fit into the type")]
+    fn rejects_gcd_of_min_and_zero() {
+        let _ = gcd(i64::MIN, 0);
+    }
+}
@@ -0,0 +1,167 @@
+/// A cache for recursive solutions.
+/// [`Memo::solve`] runs a closure that receives a `recurse` function for its sub-problems,
+/// so the cache does not need to be threaded through the recursion by hand:
+///
+/// ```
+/// use advent_of_code::memo::Memo;
+///
+/// let mut memo = Memo::new();
+/// let fibonacci = |recurse: &mut dyn FnMut(u64) -> u64, n: u64| {
+///     if n < 2 { n } else { recurse(n - 1) + recurse(n - 2) }
+/// };
+/// assert_eq!(memo.solve(80, &fibonacci), 23_416_728_348_467_685);
+/// ```
+use std::{collections::HashMap, fmt::Display, hash::Hash};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: usize,
+    pub misses: usize,
+    pub entries: usize,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, {} entries",
+            self.hits, self.misses, self.entries
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Memo<K, V> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The cached value for `key`, without counting a hit or miss.
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// The cached value for `key`, or the result of `f`, which is cached.
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce() -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = f();
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Solves `key` with `f`, which calls `recurse` to solve sub-problems.
+    /// The results of `key` and all sub-problems are cached.
+    pub fn solve<F>(&mut self, key: K, f: &F) -> V
+    where
+        F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = f(&mut |sub| self.solve(sub, f), key.clone());
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Empties the cache and resets the statistics, e.g. between parts.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// How often the cache was used, for debugging.
+    #[must_use]
+    pub fn stats(&self) -> Stats {
+        Stats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.cache.len(),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Memo, Stats};
+
+    /// Number of paths through a grid, moving right or down.
+    fn paths(recurse: &mut dyn FnMut((u64, u64)) -> u64, (x, y): (u64, u64)) -> u64 {
+        if x == 0 || y == 0 {
+            1
+        } else {
+            recurse((x - 1, y)) + recurse((x, y - 1))
+        }
+    }
+
+    #[test]
+    fn solves_recursively() {
+        let mut memo = Memo::new();
+        assert_eq!(memo.solve((16, 16), &paths), 601_080_390);
+        assert_eq!(memo.get(&(2, 2)), Some(&6));
+
+        // every sub-problem is computed once, and asked for at most twice.
+        let stats = memo.stats();
+        assert_eq!(stats.misses, memo.len());
+        assert!(stats.hits <= stats.misses);
+
+        assert_eq!(memo.solve((16, 16), &paths), 601_080_390);
+        assert_eq!(memo.stats().hits, stats.hits + 1);
+    }
+
+    #[test]
+    fn caches_values() {
+        let mut memo = Memo::new();
+        let mut calls = 0;
+        for key in [1, 2, 1, 1] {
+            memo.get_or_insert_with(key, || {
+                calls += 1;
+                key * 10
+            });
+        }
+
+        assert_eq!(calls, 2);
+        assert_eq!(memo.stats(), Stats { hits: 2, misses: 2, entries: 2 });
+        assert_eq!(memo.stats().to_string(), "2 hits, 2 misses, 2 entries");
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats(), Stats::default());
+    }
+}
@@ -0,0 +1,249 @@
+/// Helpers for parsing puzzle input.
+/// Errors point at the offending line and column of the text that was passed in. Helpers usually
+/// receive slices of the input, so errors also remember the position of the offending text and
+/// [`ParseError::locate`] moves it into the full input. [`lines_with`] does this automatically.
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-indexed line of the error.
+    pub line: usize,
+    /// 1-indexed column of the error, counted in characters.
+    pub column: usize,
+    pub message: String,
+    /// Address of the offending text, used to locate it in a larger input.
+    pub(crate) address: usize,
+}
+
+impl ParseError {
+    /// Creates an error pointing at the start of `at`, which should be a slice of the input.
+    /// Its line and column are 1 until the error is located with [`ParseError::locate`].
+    #[must_use]
+    pub fn new(at: &str, message: impl Into<String>) -> Self {
+        Self {
+            line: 1,
+            column: 1,
+            message: message.into(),
+            address: at.as_ptr() as usize,
+        }
+    }
+
+    /// Resolves the line and column of the error in `source`.
+    /// The error is returned unchanged if it does not point into `source`.
+    #[must_use]
+    pub fn locate(mut self, source: &str) -> Self {
+        let start = source.as_ptr() as usize;
+        let Some(offset) = self.address.checked_sub(start) else {
+            return self;
+        };
+
+        if offset > source.len() || !source.is_char_boundary(offset) {
+            return self;
+        }
+
+        let before = &source[..offset];
+        self.line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        self.column = before[line_start..].chars().count() + 1;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Byte ranges of the integers in `s`. A `-` counts as a sign unless it follows a digit, like in `1-3`.
+fn int_spans(s: &str) -> Vec<(usize, usize)> {
+    let bytes = s.as_bytes();
+    let mut spans = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let is_sign = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+
+        if !is_sign && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        spans.push((start, i));
+    }
+
+    spans
+}
+
+fn parse_span<T: FromStr>(s: &str, (start, end): (usize, usize)) -> Result<T, ParseError> {
+    let token = &s[start..end];
+    token.parse().map_err(|_| {
+        ParseError::new(
+            token,
+            format!("`{token}` is not a valid {}", std::any::type_name::<T>()),
+        )
+    })
+}
+
+/// Extracts all integers from `s`, skipping any other characters. E.g. `x=-3, y=12` yields `[-3, 12]`.
+pub fn ints<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    int_spans(s)
+        .into_iter()
+        .map(|span| parse_span(s, span))
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(s))
+}
+
+/// Extracts the first integer from `s`. E.g. `Game 12` yields `12`.
+pub fn int<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    match int_spans(s).first() {
+        Some(&span) => parse_span(s, span),
+        None => Err(ParseError::new(s, "expected an integer")),
+    }
+    .map_err(|e| e.locate(s))
+}
+
+/// Splits `s` at the first `separator`, e.g. `Card 1: 41 48` at `:`. Both parts are trimmed.
+pub fn key_value<'a>(s: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    let trimmed = s.trim();
+    trimmed
+        .split_once(separator)
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .ok_or_else(|| {
+            ParseError::new(&trimmed[trimmed.len()..], format!("expected `{separator}`")).locate(s)
+        })
+}
+
+/// Splits `input` into sections separated by blank lines. Sections keep their line breaks.
+#[must_use]
+pub fn split_blocks(input: &str) -> Vec<&str> {
+    let mut blocks = vec![];
+    let mut start: Option<usize> = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        if content.trim().is_empty() {
+            if let Some(s) = start.take() {
+                blocks.push(&input[s..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+
+        offset += line.len();
+    }
+
+    if let Some(s) = start {
+        blocks.push(&input[s..end]);
+    }
+
+    blocks
+}
+
+/// Parses every line of `input` with `f`. Errors are located in `input`.
+pub fn lines_with<'a, T>(
+    input: &'a str,
+    f: impl Fn(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(f)
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.locate(input))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{int, ints, key_value, lines_with, split_blocks, ParseError};
+
+    #[test]
+    fn extracts_integers() {
+        assert_eq!(ints::<i32>("x=-3, y=12").unwrap(), vec![-3, 12]);
+        assert_eq!(ints::<u32>("1-3 a: 45").unwrap(), vec![1, 3, 45]);
+        assert_eq!(ints::<u64>("  79 14 55 13 ").unwrap(), vec![79, 14, 55, 13]);
+        assert_eq!(ints::<i32>("no numbers - here").unwrap(), vec![]);
+        assert_eq!(int::<u32>("Game 12").unwrap(), 12);
+    }
+
+    #[test]
+    fn reports_invalid_integers() {
+        let err = ints::<u8>("1 300").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+        assert_eq!(err.message, "`300` is not a valid u8");
+
+        let err = ints::<u8>("1 2\n3 400").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = int::<u32>("Game 999999999999").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+
+        let err = int::<u32>("Game").unwrap_err();
+        assert_eq!(err.message, "expected an integer");
+    }
+
+    #[test]
+    fn splits_key_values() {
+        assert_eq!(
+            key_value("Card 1: 41 48 | 83", ":").unwrap(),
+            ("Card 1", "41 48 | 83")
+        );
+        assert_eq!(key_value(" 3 blue", " ").unwrap(), ("3", "blue"));
+
+        let err = key_value("b 2", ":").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 4));
+
+        let input = "a: 1\nb 2";
+        let err = key_value(input.lines().nth(1).unwrap(), ":")
+            .unwrap_err()
+            .locate(input);
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+
+    #[test]
+    fn splits_blocks() {
+        let input = "seeds: 1 2\n\nsoil:\n1 2 3\n4 5 6\n\n\nwater:\n7 8 9\n";
+        assert_eq!(
+            split_blocks(input),
+            vec!["seeds: 1 2", "soil:\n1 2 3\n4 5 6", "water:\n7 8 9"]
+        );
+        assert!(split_blocks("\n\n").is_empty());
+    }
+
+    #[test]
+    fn locates_errors_in_lines() {
+        let input = "1 2\n3 x4 -5\n6 7777";
+        assert_eq!(
+            lines_with(input, ints::<i8>).unwrap_err(),
+            ParseError {
+                line: 3,
+                column: 3,
+                message: "`7777` is not a valid i8".into(),
+                address: input[input.len() - 4..].as_ptr() as usize,
+            }
+        );
+
+        let input = "a\n\nb: 1\nc 2";
+        let blocks = split_blocks(input);
+        let err = lines_with(blocks[1], |l| key_value(l, ":")).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        // errors in a block can be located in the full input as well.
+        let err = err.locate(input);
+        assert_eq!((err.line, err.column), (4, 4));
+    }
+}
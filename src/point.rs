@@ -0,0 +1,330 @@
+/// Points, vectors and directions on integer coordinates.
+/// The y-axis points down like in puzzle inputs, so [`Direction::N`] moves to a smaller y.
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::grid::Position;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+/// Implements component-wise arithmetic and scaling for a point type.
+macro_rules! impl_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = Self;
+
+            fn mul(self, factor: i64) -> Self {
+                Self { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl $point {
+            pub const ORIGIN: Self = Self { $($field: 0),+ };
+
+            /// The sum of the absolute differences of all coordinates.
+            #[must_use]
+            pub fn manhattan(self, other: Self) -> u64 {
+                0_u64 $(+ self.$field.abs_diff(other.$field))+
+            }
+
+            /// The largest absolute difference of any coordinate, i.e. the number of king moves.
+            #[must_use]
+            pub fn chebyshev(self, other: Self) -> u64 {
+                0_u64 $(.max(self.$field.abs_diff(other.$field)))+
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+impl Point2 {
+    #[must_use]
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    #[must_use]
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// The 4 orthogonal neighbours, clockwise starting at the top.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::CARDINAL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// The 8 orthogonal and diagonal neighbours, clockwise starting at the top.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction::ALL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// Converts the point to a grid position, if both coordinates are non-negative.
+    #[must_use]
+    pub fn to_position(self) -> Option<Position> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+}
+
+impl Point3 {
+    #[must_use]
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl From<Position> for Point2 {
+    /// Converts a `(row, column)` grid position.
+    fn from((row, column): Position) -> Self {
+        Self::new(
+            i64::try_from(column).expect("column out of range"),
+            i64::try_from(row).expect("row out of range"),
+        )
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// A compass direction, including diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseDirectionError(char);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid direction `{}`, expecting one of U, D, L, R, ^, v, <, >",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+impl Direction {
+    /// All directions, clockwise starting at north.
+    pub const ALL: [Direction; 8] = [
+        Direction::N,
+        Direction::NE,
+        Direction::E,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+    ];
+
+    /// The orthogonal directions, clockwise starting at north.
+    pub const CARDINAL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Rotates clockwise by `steps` times 45 degrees. Negative steps rotate counterclockwise.
+    #[must_use]
+    pub fn rotate(self, steps: i32) -> Self {
+        // reduce first, so that large steps do not overflow.
+        let steps = steps.rem_euclid(8).unsigned_abs() as usize;
+        Self::ALL[(self.index() + steps) % 8]
+    }
+
+    /// Turns 90 degrees clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Turns 90 degrees counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        self.rotate(-2)
+    }
+
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    #[must_use]
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// The vector of a single step in this direction.
+    #[must_use]
+    pub fn offset(self) -> Point2 {
+        let (x, y) = match self {
+            Direction::N => (0, -1),
+            Direction::NE => (1, -1),
+            Direction::E => (1, 0),
+            Direction::SE => (1, 1),
+            Direction::S => (0, 1),
+            Direction::SW => (-1, 1),
+            Direction::W => (-1, 0),
+            Direction::NW => (-1, -1),
+        };
+        Point2::new(x, y)
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    /// Parses `U`/`D`/`L`/`R` as well as `^`/`v`/`<`/`>`.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | '^' => Ok(Direction::N),
+            'R' | '>' => Ok(Direction::E),
+            'D' | 'v' => Ok(Direction::S),
+            'L' | '<' => Ok(Direction::W),
+            c => Err(ParseDirectionError(c)),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, ParseDirectionError, Point2, Point3};
+
+    #[test]
+    fn computes_with_points() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(-3, 5);
+        assert_eq!(a + b, Point2::new(-2, 7));
+        assert_eq!(a - b, Point2::new(4, -3));
+        assert_eq!(a * 3, Point2::new(3, 6));
+        assert_eq!(-a, Point2::new(-1, -2));
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+
+        assert_eq!(
+            Point3::new(1, 2, 3) + Point3::new(1, 1, 1),
+            Point3::new(2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn measures_distances() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(-3, 5);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point3::ORIGIN.manhattan(Point3::new(1, -2, 3)), 6);
+        assert_eq!(Point3::ORIGIN.chebyshev(Point3::new(1, -2, 3)), 3);
+    }
+
+    #[test]
+    fn turns_directions() {
+        assert_eq!(Direction::N.turn_right(), Direction::E);
+        assert_eq!(Direction::N.turn_left(), Direction::W);
+        assert_eq!(Direction::NE.turn_left(), Direction::NW);
+        assert_eq!(Direction::SW.opposite(), Direction::NE);
+        assert_eq!(Direction::W.rotate(1), Direction::NW);
+        assert_eq!(Direction::N.rotate(-9), Direction::NW);
+        assert_eq!(Direction::NW.rotate(i32::MAX), Direction::W);
+        assert_eq!(Direction::N.rotate(i32::MIN), Direction::N);
+        assert!(Direction::SE.is_diagonal());
+        assert!(!Direction::S.is_diagonal());
+    }
+
+    #[test]
+    fn steps_in_directions() {
+        let p = Point2::new(2, 2);
+        assert_eq!(p.step(Direction::N), Point2::new(2, 1));
+        assert_eq!(p.step(Direction::SE), Point2::new(3, 3));
+        assert_eq!(p.neighbours4().count(), 4);
+        assert_eq!(p.neighbours8().count(), 8);
+        assert_eq!(p.to_position(), Some((2, 2)));
+        assert_eq!(Point2::new(-1, 0).to_position(), None);
+        assert_eq!(Point2::from((1, 4)), Point2::new(4, 1));
+    }
+
+    #[test]
+    fn parses_directions() {
+        let parsed: Vec<Direction> = "UR<v"
+            .chars()
+            .map(|c| Direction::try_from(c).unwrap())
+            .collect();
+        assert_eq!(
+            parsed,
+            vec![Direction::N, Direction::E, Direction::W, Direction::S]
+        );
+        assert_eq!(Direction::try_from('x'), Err(ParseDirectionError('x')));
+    }
+}
@@ -0,0 +1,414 @@
+/// Graph searches over arbitrary states: BFS, DFS, Dijkstra and A*.
+/// The graph is never built explicitly. Searches start from one or more states and call a
+/// `neighbours` closure to expand a state, so they work for grid positions as well as for
+/// states like `(position, direction, keys)`.
+/// Goal searches return the path from a start to the first goal, including both ends.
+/// `*_reach` searches explore everything reachable and return a [`Reached`] map.
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Costs of edges and paths. [`Default::default`] must be zero.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// All states reached by a search, with their cost and the state they were reached from.
+#[derive(Debug, Clone)]
+pub struct Reached<S, C> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    costs: Vec<C>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Reached<S, C> {
+    fn new() -> Self {
+        Self {
+            states: vec![],
+            index: HashMap::new(),
+            costs: vec![],
+            parents: vec![],
+        }
+    }
+
+    fn insert(&mut self, state: S, cost: C, parent: Option<usize>) -> usize {
+        let id = self.states.len();
+        self.index.insert(state.clone(), id);
+        self.states.push(state);
+        self.costs.push(cost);
+        self.parents.push(parent);
+        id
+    }
+
+    fn path_to(&self, mut id: usize) -> Vec<S> {
+        let mut path = vec![self.states[id].clone()];
+        while let Some(parent) = self.parents[id] {
+            path.push(self.states[parent].clone());
+            id = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// The cost of the cheapest path found to `state`.
+    #[must_use]
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.index.get(state).map(|&id| self.costs[id])
+    }
+
+    /// The path found to `state`, starting at one of the start states.
+    #[must_use]
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        self.index.get(state).map(|&id| self.path_to(id))
+    }
+
+    #[must_use]
+    pub fn contains(&self, state: &S) -> bool {
+        self.index.contains_key(state)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// All reached states with their cost, in the order they were discovered.
+    pub fn iter(&self) -> impl Iterator<Item = (&S, C)> {
+        self.states.iter().zip(self.costs.iter().copied())
+    }
+}
+
+fn bfs_search<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Reached<S, usize>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut reached = Reached::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if !reached.contains(&start) {
+            queue.push_back(reached.insert(start, 0, None));
+        }
+    }
+
+    while let Some(id) = queue.pop_front() {
+        let state = reached.states[id].clone();
+        if is_goal(&state) {
+            return (reached, Some(id));
+        }
+
+        let cost = reached.costs[id] + 1;
+        for next in neighbours(&state) {
+            if !reached.contains(&next) {
+                queue.push_back(reached.insert(next, cost, Some(id)));
+            }
+        }
+    }
+
+    (reached, None)
+}
+
+/// Shortest path in steps from `start` to a state matching `is_goal`.
+pub fn bfs<S, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_multi([start], neighbours, is_goal)
+}
+
+/// Shortest path in steps from any of `starts` to a state matching `is_goal`.
+pub fn bfs_multi<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let (reached, goal) = bfs_search(starts, neighbours, is_goal);
+    goal.map(|id| reached.path_to(id))
+}
+
+/// All states reachable from `starts`, with their distance in steps to the nearest start.
+pub fn bfs_reach<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+) -> Reached<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_search(starts, neighbours, |_| false).0
+}
+
+/// Any path from `start` to a state matching `is_goal`, exploring depth first.
+/// The path is not necessarily the shortest one.
+pub fn dfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut reached = Reached::new();
+    let mut stack = vec![reached.insert(start, 0, None)];
+
+    while let Some(id) = stack.pop() {
+        let state = reached.states[id].clone();
+        if is_goal(&state) {
+            return Some(reached.path_to(id));
+        }
+
+        let depth = reached.costs[id] + 1;
+        for next in neighbours(&state) {
+            if !reached.contains(&next) {
+                stack.push(reached.insert(next, depth, Some(id)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm, or A* if `heuristic` is not always zero.
+/// The heuristic must never overestimate the remaining cost and must be consistent.
+fn weighted_search<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Reached<S, C>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut reached = Reached::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if !reached.contains(&start) {
+            let estimate = heuristic(&start);
+            let id = reached.insert(start, C::default(), None);
+            queue.push(Reverse((estimate, C::default(), id)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, id))) = queue.pop() {
+        // a cheaper path to this state has been found after it was queued.
+        if cost > reached.costs[id] {
+            continue;
+        }
+
+        let state = reached.states[id].clone();
+        if is_goal(&state) {
+            return (reached, Some(id));
+        }
+
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            let next_id = match reached.index.get(&next) {
+                Some(&next_id) if reached.costs[next_id] <= next_cost => continue,
+                Some(&next_id) => {
+                    reached.costs[next_id] = next_cost;
+                    reached.parents[next_id] = Some(id);
+                    next_id
+                }
+                None => reached.insert(next, next_cost, Some(id)),
+            };
+
+            let estimate = heuristic(&reached.states[next_id]);
+            queue.push(Reverse((next_cost + estimate, next_cost, next_id)));
+        }
+    }
+
+    (reached, None)
+}
+
+/// Cheapest path from `start` to a state matching `is_goal`, and its cost.
+/// `neighbours` returns the next states together with the cost of moving there.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    dijkstra_multi([start], neighbours, is_goal)
+}
+
+/// Cheapest path from any of `starts` to a state matching `is_goal`, and its cost.
+pub fn dijkstra_multi<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (reached, goal) = weighted_search(starts, neighbours, |_| C::default(), is_goal);
+    goal.map(|id| (reached.path_to(id), reached.costs[id]))
+}
+
+/// All states reachable from `starts`, with the cost of the cheapest path from any start.
+pub fn dijkstra_reach<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+) -> Reached<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    weighted_search(starts, neighbours, |_| C::default(), |_| false).0
+}
+
+/// Cheapest path from `start` to a state matching `is_goal`, guided by `heuristic`.
+/// The heuristic estimates the remaining cost, e.g. the Manhattan distance to the goal.
+/// It must never overestimate, otherwise the path might not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (reached, goal) = weighted_search([start], neighbours, heuristic, is_goal);
+    goal.map(|id| (reached.path_to(id), reached.costs[id]))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{astar, bfs, bfs_multi, bfs_reach, dfs, dijkstra, dijkstra_multi, dijkstra_reach};
+    use crate::grid::{Grid, Position};
+
+    /// A weighted graph where the direct edge from `a` to `d` is more expensive than the detour.
+    fn get_graph() -> HashMap<char, Vec<(char, u32)>> {
+        HashMap::from([
+            ('a', vec![('b', 1), ('d', 10)]),
+            ('b', vec![('c', 2)]),
+            ('c', vec![('d', 3)]),
+            ('d', vec![('e', 1)]),
+            ('e', vec![]),
+            ('f', vec![('a', 1)]),
+        ])
+    }
+
+    fn get_maze() -> Grid<char> {
+        Grid::parse("S.#.\n..#.\n.#..\n...E").unwrap()
+    }
+
+    fn maze_neighbours(maze: &Grid<char>, position: Position) -> Vec<Position> {
+        maze.neighbours4(position)
+            .filter(|&p| maze[p] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn searches_breadth_first() {
+        let graph = get_graph();
+        let edges = |s: &char| graph[s].iter().map(|&(n, _)| n).collect::<Vec<_>>();
+
+        assert_eq!(bfs('a', edges, |&s| s == 'e'), Some(vec!['a', 'd', 'e']));
+        assert_eq!(bfs('a', edges, |&s| s == 'a'), Some(vec!['a']));
+        assert_eq!(bfs('b', edges, |&s| s == 'f'), None);
+        assert_eq!(
+            bfs_multi(['c', 'f'], edges, |&s| s == 'b'),
+            Some(vec!['f', 'a', 'b'])
+        );
+
+        let reached = bfs_reach(['a'], edges);
+        assert_eq!(reached.len(), 5);
+        assert_eq!(reached.cost(&'c'), Some(2));
+        assert_eq!(reached.cost(&'f'), None);
+        assert_eq!(reached.path(&'e'), Some(vec!['a', 'd', 'e']));
+    }
+
+    #[test]
+    fn searches_mazes() {
+        let maze = get_maze();
+        let start = maze.find(|&c| c == 'S').unwrap();
+        let end = maze.find(|&c| c == 'E').unwrap();
+
+        let path = bfs(start, |&p| maze_neighbours(&maze, p), |&p| p == end).unwrap();
+        assert_eq!(path.len() - 1, 6);
+
+        let path = dfs(start, |&p| maze_neighbours(&maze, p), |&p| p == end).unwrap();
+        assert_eq!((path.first(), path.last()), (Some(&start), Some(&end)));
+        assert!(path
+            .windows(2)
+            .all(|w| maze_neighbours(&maze, w[0]).contains(&w[1])));
+
+        let manhattan = |&(r, c): &Position| r.abs_diff(end.0) + c.abs_diff(end.1);
+        let (path, cost) = astar(
+            start,
+            |&p| maze_neighbours(&maze, p).into_iter().map(|n| (n, 1)),
+            manhattan,
+            |&p| p == end,
+        )
+        .unwrap();
+        assert_eq!((path.len() - 1, cost), (6, 6));
+
+        let distances = bfs_reach([end], |&p| maze_neighbours(&maze, p));
+        assert_eq!(distances.cost(&(0, 3)), Some(3));
+        assert_eq!(distances.len(), 13);
+    }
+
+    #[test]
+    fn searches_weighted_graphs() {
+        let graph = get_graph();
+        let edges = |s: &char| graph[s].clone();
+
+        assert_eq!(
+            dijkstra('a', edges, |&s| s == 'e'),
+            Some((vec!['a', 'b', 'c', 'd', 'e'], 7))
+        );
+        assert_eq!(dijkstra('e', edges, |&s| s == 'a'), None);
+        assert_eq!(
+            dijkstra_multi(['b', 'f'], edges, |&s| s == 'd'),
+            Some((vec!['b', 'c', 'd'], 5))
+        );
+        assert_eq!(
+            astar('a', edges, |_| 0, |&s| s == 'd'),
+            Some((vec!['a', 'b', 'c', 'd'], 6))
+        );
+
+        let reached = dijkstra_reach(['a'], edges);
+        assert_eq!(reached.cost(&'d'), Some(6));
+        assert_eq!(reached.path(&'d'), Some(vec!['a', 'b', 'c', 'd']));
+        assert_eq!(reached.iter().map(|(_, cost)| cost).max(), Some(7));
+    }
+}
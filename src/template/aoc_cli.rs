@@ -4,6 +4,7 @@ use std::{
     process::{Command, Output, Stdio},
 };
 
+use crate::template::{puzzle::get_puzzle_path, session};
 use crate::Day;
 
 #[derive(Debug)]
@@ -35,42 +36,42 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
-
-    let args = build_args(
-        "read",
-        &[
-            "--description-only".into(),
-            "--puzzle-file".into(),
-            puzzle_path,
-        ],
-        day,
-    );
-
-    call_aoc_cli(&args)
+/// Which files `download` fetches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadTarget {
+    All,
+    InputOnly,
+    PuzzleOnly,
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
+pub fn download(day: Day, target: DownloadTarget) -> Result<Output, AocCommandError> {
     let input_path = get_input_path(day);
     let puzzle_path = get_puzzle_path(day);
 
-    let args = build_args(
-        "download",
-        &[
-            "--overwrite".into(),
-            "--input-file".into(),
-            input_path.to_string(),
-            "--puzzle-file".into(),
-            puzzle_path.to_string(),
-        ],
-        day,
-    );
+    let mut download_args: Vec<String> = vec![
+        "--overwrite".into(),
+        "--input-file".into(),
+        input_path.to_string(),
+        "--puzzle-file".into(),
+        puzzle_path.to_string(),
+    ];
+
+    match target {
+        DownloadTarget::All => {}
+        DownloadTarget::InputOnly => download_args.push("--input-only".into()),
+        DownloadTarget::PuzzleOnly => download_args.push("--puzzle-only".into()),
+    }
+
+    let args = build_args("download", &download_args, day);
 
     let output = call_aoc_cli(&args)?;
     println!("---");
-    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    if target != DownloadTarget::PuzzleOnly {
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    }
+    if target != DownloadTarget::InputOnly {
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    }
     Ok(output)
 }
 
@@ -79,18 +80,18 @@ pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandErro
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    // the response is captured so callers can tell whether the answer was accepted.
+    let output = call_aoc_cli_piped(&args)?;
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+    Ok(output)
 }
 
 fn get_input_path(day: Day) -> String {
     format!("data/inputs/{day}.txt")
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
-}
-
-fn get_year() -> Option<u16> {
+pub fn get_year() -> Option<u16> {
     match std::env::var("AOC_YEAR") {
         Ok(x) => x.parse().ok().or(None),
         Err(_) => None,
@@ -100,6 +101,14 @@ fn get_year() -> Option<u16> {
 fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
+    // prefer the token stored via `cargo auth set` over the ones aoc-cli looks up on its own.
+    if let Ok(path) = session::get_session_path() {
+        if path.exists() {
+            cmd_args.push("--session-file".into());
+            cmd_args.push(path.to_string_lossy().to_string());
+        }
+    }
+
     if let Some(year) = get_year() {
         cmd_args.push("--year".into());
         cmd_args.push(year.to_string());
@@ -111,10 +120,18 @@ fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
 }
 
 fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
+    run_aoc_cli(args, Stdio::inherit())
+}
+
+fn call_aoc_cli_piped(args: &[String]) -> Result<Output, AocCommandError> {
+    run_aoc_cli(args, Stdio::piped())
+}
+
+fn run_aoc_cli(args: &[String], stdout: Stdio) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
     let output = Command::new("aoc")
         .args(args)
-        .stdout(Stdio::inherit())
+        .stdout(stdout)
         .stderr(Stdio::inherit())
         .output()
         .map_err(|_| AocCommandError::CommandNotCallable)?;
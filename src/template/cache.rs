@@ -0,0 +1,229 @@
+/// Module that keeps track of downloaded inputs and puzzle descriptions.
+/// Every download is recorded with a timestamp and a checksum of the file contents, which lets
+/// `download` skip files that are up to date and detect inputs that were edited locally.
+use std::{fmt::Display, fs, io, time::SystemTime};
+
+use crate::template::datetime;
+use crate::Day;
+
+static CACHE_PATH: &str = "data/.cache";
+static HEADER: &str = "# kind\tday\tfetched_at\tchecksum";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(s) => write!(f, "{s}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Input,
+    Puzzle,
+}
+
+impl Kind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Kind::Input => "input",
+            Kind::Puzzle => "puzzle",
+        }
+    }
+
+    #[must_use]
+    pub fn get_path(self, day: Day) -> String {
+        match self {
+            Kind::Input => format!("data/inputs/{day}.txt"),
+            Kind::Puzzle => format!("data/puzzles/{day}.md"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub kind: Kind,
+    pub day: Day,
+    /// Seconds since the unix epoch.
+    pub fetched_at: i64,
+    pub checksum: u64,
+}
+
+/// State of a file on disk compared to the cache.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FileState {
+    Missing,
+    /// The file exists, but was not downloaded by the template.
+    Untracked,
+    Unchanged(Entry),
+    Modified(Entry),
+}
+
+#[derive(Debug, Default)]
+pub struct Cache {
+    entries: Vec<Entry>,
+}
+
+/// A 64-bit FNV-1a hash. Unlike `DefaultHasher`, it is stable between rust versions.
+#[must_use]
+pub fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn parse_entry(line: &str) -> Result<Entry, Error> {
+    let err = || Error::Parser(format!("Malformed cache entry: {line}"));
+
+    let fields: Vec<&str> = line.split('\t').collect();
+    let [kind, day, fetched_at, checksum] = fields[..] else {
+        return Err(err());
+    };
+
+    let kind = match kind {
+        "input" => Kind::Input,
+        "puzzle" => Kind::Puzzle,
+        _ => return Err(err()),
+    };
+
+    Ok(Entry {
+        kind,
+        day: day.parse().map_err(|_| err())?,
+        fetched_at: fetched_at.parse().map_err(|_| err())?,
+        checksum: u64::from_str_radix(checksum, 16).map_err(|_| err())?,
+    })
+}
+
+impl Cache {
+    /// Loads the cache from disk. A missing cache file is treated as an empty cache.
+    pub fn load() -> Result<Self, Error> {
+        match fs::read_to_string(CACHE_PATH) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let entries = s
+            .lines()
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .map(parse_entry)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { entries })
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        fs::write(CACHE_PATH, self.to_string())?;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn get(&self, kind: Kind, day: Day) -> Option<&Entry> {
+        self.entries.iter().find(|e| e.kind == kind && e.day == day)
+    }
+
+    /// Records that `contents` were fetched for a file just now.
+    pub fn record(&mut self, kind: Kind, day: Day, contents: &[u8]) {
+        self.entries.retain(|e| !(e.kind == kind && e.day == day));
+        self.entries.push(Entry {
+            kind,
+            day,
+            fetched_at: datetime::unix_seconds(SystemTime::now()),
+            checksum: checksum(contents),
+        });
+        self.entries.sort_by_key(|e| (e.day, e.kind.as_str()));
+    }
+
+    /// Compares the contents of a file with the cache. `contents` is [`None`] if the file does not exist.
+    #[must_use]
+    pub fn state(&self, kind: Kind, day: Day, contents: Option<&[u8]>) -> FileState {
+        match (contents, self.get(kind, day)) {
+            (None, _) => FileState::Missing,
+            (Some(_), None) => FileState::Untracked,
+            (Some(contents), Some(entry)) if checksum(contents) == entry.checksum => {
+                FileState::Unchanged(entry.clone())
+            }
+            (Some(_), Some(entry)) => FileState::Modified(entry.clone()),
+        }
+    }
+}
+
+impl Display for Cache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        for e in &self.entries {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{:016x}",
+                e.kind.as_str(),
+                e.day,
+                e.fetched_at,
+                e.checksum
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{checksum, Cache, FileState, Kind};
+    use crate::day;
+
+    #[test]
+    fn checksums_are_stable() {
+        assert_eq!(checksum(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(checksum(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let mut cache = Cache::default();
+        cache.record(Kind::Puzzle, day!(2), b"puzzle");
+        cache.record(Kind::Input, day!(1), b"input");
+
+        let parsed = Cache::parse(&cache.to_string()).unwrap();
+        assert_eq!(parsed.entries, cache.entries);
+        assert_eq!(parsed.entries[0].day, day!(1));
+    }
+
+    #[test]
+    fn rejects_malformed_entries() {
+        assert!(Cache::parse("input\t01\tyesterday\t00").is_err());
+        assert!(Cache::parse("notes\t01\t0\t00").is_err());
+    }
+
+    #[test]
+    fn detects_file_state() {
+        let mut cache = Cache::default();
+        cache.record(Kind::Input, day!(1), b"1 2 3");
+
+        assert_eq!(cache.state(Kind::Input, day!(1), None), FileState::Missing);
+        assert_eq!(
+            cache.state(Kind::Input, day!(2), Some(b"")),
+            FileState::Untracked
+        );
+        assert!(matches!(
+            cache.state(Kind::Input, day!(1), Some(b"1 2 3")),
+            FileState::Unchanged(_)
+        ));
+        assert!(matches!(
+            cache.state(Kind::Input, day!(1), Some(b"1 2 4")),
+            FileState::Modified(_)
+        ));
+    }
+}
@@ -0,0 +1,241 @@
+/// Module that renders benchmark timings to a standalone SVG bar chart.
+/// Bars are grouped per day (part 1 and part 2) and drawn on a logarithmic scale,
+/// so that days in the nanosecond range stay visible next to days that take seconds.
+use std::{fmt::Write, fs, io};
+
+use crate::template::readme_benchmarks::Timings;
+
+const BAR_WIDTH: u32 = 12;
+const BAR_GAP: u32 = 2;
+const GROUP_GAP: u32 = 10;
+const PLOT_HEIGHT: u32 = 240;
+const MARGIN_LEFT: u32 = 60;
+const MARGIN_RIGHT: u32 = 20;
+const MARGIN_TOP: u32 = 40;
+const MARGIN_BOTTOM: u32 = 50;
+/// Room for the title at the top left, so that the legend never overlaps it.
+const TITLE_WIDTH: u32 = 190;
+const LEGEND_WIDTH: u32 = 130;
+
+const COLORS: [&str; 2] = ["#9ab8d8", "#f5c542"];
+
+fn group_width() -> u32 {
+    2 * BAR_WIDTH + BAR_GAP
+}
+
+/// Returns the decades (powers of ten, in nanoseconds) that the y-axis spans.
+fn decades(timings: &[Timings]) -> (i32, i32) {
+    let values = timings
+        .iter()
+        .flat_map(|t| [t.part_1_nanos, t.part_2_nanos])
+        .flatten()
+        .filter(|&n| n > 0_f64);
+
+    let (min, max) = values.fold((f64::MAX, f64::MIN), |(min, max), n| {
+        (min.min(n), max.max(n))
+    });
+
+    if min > max {
+        return (0, 3);
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let lo = min.log10().floor() as i32;
+    #[allow(clippy::cast_possible_truncation)]
+    let hi = (max.log10().ceil() as i32).max(lo + 1);
+    (lo, hi)
+}
+
+fn format_decade(exp: i32) -> String {
+    let units = ["ns", "µs", "ms", "s"];
+    let unit = usize::try_from(exp.div_euclid(3))
+        .unwrap_or(0)
+        .min(units.len() - 1);
+    #[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+    let value = 10_u64.pow((exp - 3 * unit as i32).max(0).unsigned_abs());
+    format!("{value}{}", units[unit])
+}
+
+/// Height of a bar for `nanos`, on a log scale between `10^lo` and `10^hi` nanoseconds.
+fn bar_height(nanos: f64, lo: i32, hi: i32) -> f64 {
+    if nanos <= 0_f64 {
+        return 0_f64;
+    }
+    let ratio = (nanos.log10() - f64::from(lo)) / f64::from(hi - lo);
+    ratio.clamp(0_f64, 1_f64) * f64::from(PLOT_HEIGHT)
+}
+
+/// Renders the timings of both parts of each day to an SVG document.
+#[must_use]
+pub fn render(timings: &[Timings]) -> String {
+    let mut timings = timings.to_vec();
+    timings.sort_by_key(|t| t.day);
+
+    let (lo, hi) = decades(&timings);
+    let count = u32::try_from(timings.len()).unwrap_or(0).max(1);
+    let plot_width = count * group_width() + (count + 1) * GROUP_GAP;
+    // few days make a narrow plot, the header still needs room for the title and legend.
+    let width = MARGIN_LEFT + plot_width.max(TITLE_WIDTH + LEGEND_WIDTH) + MARGIN_RIGHT;
+    let height = MARGIN_TOP + PLOT_HEIGHT + MARGIN_BOTTOM;
+    let baseline = MARGIN_TOP + PLOT_HEIGHT;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="sans-serif" font-size="11">"#
+    );
+    let _ = writeln!(
+        svg,
+        r##"<rect width="{width}" height="{height}" fill="#0f0f23"/>"##
+    );
+    let _ = writeln!(
+        svg,
+        r##"<text x="{MARGIN_LEFT}" y="20" fill="#cccccc" font-size="13">Runtime per day (log scale)</text>"##
+    );
+
+    // legend
+    for (i, color) in COLORS.iter().enumerate() {
+        let x = width - MARGIN_RIGHT - LEGEND_WIDTH + 65 * u32::try_from(i).unwrap_or(0);
+        let _ = writeln!(
+            svg,
+            r##"<rect x="{x}" y="11" width="10" height="10" fill="{color}"/><text x="{}" y="20" fill="#cccccc">Part {}</text>"##,
+            x + 14,
+            i + 1
+        );
+    }
+
+    // y-axis gridlines, one per decade.
+    for exp in lo..=hi {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let y = baseline - bar_height(10_f64.powi(exp), lo, hi).round() as u32;
+        let _ = writeln!(
+            svg,
+            r##"<line x1="{MARGIN_LEFT}" y1="{y}" x2="{}" y2="{y}" stroke="#333340"/><text x="{}" y="{}" fill="#999999" text-anchor="end">{}</text>"##,
+            MARGIN_LEFT + plot_width,
+            MARGIN_LEFT - 6,
+            y + 4,
+            format_decade(exp)
+        );
+    }
+
+    for (i, timing) in timings.iter().enumerate() {
+        let i = u32::try_from(i).unwrap_or(0);
+        let x = MARGIN_LEFT + GROUP_GAP + i * (group_width() + GROUP_GAP);
+
+        let parts = [
+            (timing.part_1_nanos, timing.part_1.as_deref()),
+            (timing.part_2_nanos, timing.part_2.as_deref()),
+        ];
+
+        for (part, (nanos, label)) in parts.iter().enumerate() {
+            let (Some(nanos), Some(label)) = (nanos, label) else {
+                continue;
+            };
+            let bar_x = x + u32::try_from(part).unwrap_or(0) * (BAR_WIDTH + BAR_GAP);
+            let h = bar_height(*nanos, lo, hi).max(1_f64);
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{bar_x}" y="{:.1}" width="{BAR_WIDTH}" height="{h:.1}" fill="{}"><title>Day {} part {}: {label}</title></rect>"#,
+                f64::from(baseline) - h,
+                COLORS[part],
+                timing.day,
+                part + 1
+            );
+        }
+
+        let _ = writeln!(
+            svg,
+            r##"<text x="{}" y="{}" fill="#cccccc" text-anchor="middle">{}</text>"##,
+            x + group_width() / 2,
+            baseline + 16,
+            timing.day
+        );
+    }
+
+    let _ = writeln!(
+        svg,
+        r##"<line x1="{MARGIN_LEFT}" y1="{baseline}" x2="{}" y2="{baseline}" stroke="#999999"/>"##,
+        MARGIN_LEFT + plot_width
+    );
+    let _ = writeln!(
+        svg,
+        r##"<text x="{}" y="{}" fill="#999999" text-anchor="middle">Day</text>"##,
+        MARGIN_LEFT + plot_width / 2,
+        baseline + 36
+    );
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Writes the chart to `path`.
+pub fn write(timings: &[Timings], path: &str) -> Result<(), io::Error> {
+    fs::write(path, render(timings))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{bar_height, decades, format_decade, render};
+    use crate::day;
+    use crate::template::readme_benchmarks::Timings;
+
+    fn get_mock_timings() -> Vec<Timings> {
+        vec![
+            Timings {
+                part_1: Some("120ns".into()),
+                part_2: Some("2.0ms".into()),
+                part_1_nanos: Some(120_f64),
+                part_2_nanos: Some(2e+6),
+                ..Timings::new(day!(2))
+            },
+            Timings {
+                part_1: Some("15.0µs".into()),
+                part_1_nanos: Some(15e+3),
+                ..Timings::new(day!(1))
+            },
+        ]
+    }
+
+    #[test]
+    fn spans_decades_of_timings() {
+        assert_eq!(decades(&get_mock_timings()), (2, 7));
+        assert_eq!(decades(&[]), (0, 3));
+    }
+
+    #[test]
+    fn formats_decades() {
+        assert_eq!(format_decade(0), "1ns");
+        assert_eq!(format_decade(2), "100ns");
+        assert_eq!(format_decade(4), "10µs");
+        assert_eq!(format_decade(9), "1s");
+        assert_eq!(format_decade(11), "100s");
+    }
+
+    #[test]
+    fn scales_logarithmically() {
+        assert!((bar_height(1e+4, 2, 6) - 120_f64).abs() < 1e-9);
+        assert!((bar_height(1e+6, 2, 6) - 240_f64).abs() < 1e-9);
+        assert!(bar_height(0_f64, 2, 6) == 0_f64);
+    }
+
+    #[test]
+    fn renders_bars_per_part() {
+        let svg = render(&get_mock_timings());
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("<title>").count(), 3);
+        assert!(svg.contains("<title>Day 01 part 1: 15.0µs</title>"));
+        assert!(svg.contains("<title>Day 02 part 2: 2.0ms</title>"));
+        // days are sorted.
+        assert!(svg.find(">01</text>").unwrap() < svg.find(">02</text>").unwrap());
+    }
+
+    #[test]
+    fn renders_single_day() {
+        let svg = render(&get_mock_timings()[1..]);
+        assert_eq!(svg.matches("<title>").count(), 1);
+        // the legend fits into the chart.
+        assert!(svg.contains(r#"width="400""#));
+        assert!(svg.contains(r#"<rect x="250" y="11""#));
+        assert!(svg.contains(r#"<rect x="315" y="11""#));
+    }
+}
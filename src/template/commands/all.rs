@@ -1,13 +1,24 @@
 use std::io;
 
 use crate::template::{
-    readme_benchmarks::{self, Timings},
+    chart, csv,
+    last_run::LastRun,
+    readme_benchmarks::{self, TableOptions, Timings},
+    stars::Stars,
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
 use crate::{all_days, Day};
 
-pub fn handle(is_release: bool, is_timed: bool) {
+pub fn handle(
+    is_release: bool,
+    is_timed: bool,
+    table: &TableOptions,
+    chart: Option<&str>,
+    csv: Option<&str>,
+) {
     let mut timings: Vec<Timings> = vec![];
+    // unlike `timings`, this includes days that have not been solved yet.
+    let mut rows: Vec<Timings> = vec![];
 
     all_days().for_each(|day| {
         if day > 1 {
@@ -21,22 +32,51 @@ pub fn handle(is_release: bool, is_timed: bool) {
 
         if output.is_empty() {
             println!("Not solved.");
+            rows.push(Timings::new(day));
         } else {
             let val = child_commands::parse_exec_time(&output, day);
+            rows.push(val.clone());
             timings.push(val);
         }
     });
 
+    let result = LastRun::load().and_then(|mut last_run| {
+        timings.iter().for_each(|t| last_run.record(t, is_timed));
+        last_run.save()
+    });
+
+    if let Err(e) = result {
+        eprintln!("Failed to save results of this run: {e}");
+    }
+
+    if let Some(path) = csv {
+        let result = Stars::load()
+            .map_err(|e| e.to_string())
+            .and_then(|stars| csv::write(&rows, &stars, path).map_err(|e| e.to_string()));
+
+        match result {
+            Ok(()) => println!("Successfully wrote timings to {path}."),
+            Err(e) => eprintln!("Failed to write timings to {path}: {e}"),
+        }
+    }
+
     if is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
         println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
 
+        if let Some(path) = chart {
+            match chart::write(&timings, path) {
+                Ok(()) => println!("Successfully wrote benchmark chart to {path}."),
+                Err(e) => eprintln!("Failed to write benchmark chart to {path}: {e}"),
+            }
+        }
+
         if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
+            match readme_benchmarks::update(timings, total_millis, table) {
+                Ok(()) => println!("Successfully updated {} with benchmarks.", table.path),
                 Err(_) => {
-                    eprintln!("Failed to update readme with benchmarks.");
+                    eprintln!("Failed to update {} with benchmarks.", table.path);
                 }
             }
         }
@@ -63,8 +103,9 @@ pub fn get_path_for_bin(day: Day) -> String {
 
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
+pub(crate) mod child_commands {
     use super::{get_path_for_bin, Error};
+    use crate::template::{ANSI_BOLD, ANSI_RESET};
     use crate::Day;
     use std::{
         io::{BufRead, BufReader},
@@ -126,17 +167,17 @@ mod child_commands {
     }
 
     pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+        let mut timings = super::Timings::new(day);
 
         output
             .iter()
             .filter_map(|l| {
-                if !l.contains(" samples)") {
+                if let Some(memory) = parse_memory(l) {
+                    timings.memory = Some(memory.into());
+                    return None;
+                }
+
+                if !l.contains(" samples") {
                     return None;
                 }
 
@@ -146,21 +187,87 @@ mod child_commands {
                 };
 
                 let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+                let median = parse_median(l);
+                Some((part, timing_str, nanos, parse_samples(l), median))
             })
-            .for_each(|(part, timing_str, nanos)| {
+            .for_each(|(part, timing_str, nanos, samples, median)| {
                 if part.contains("Part 1") {
                     timings.part_1 = Some(timing_str.into());
+                    timings.part_1_nanos = Some(nanos);
+                    timings.part_1_samples = samples;
+                    timings.part_1_median_nanos = median;
                 } else if part.contains("Part 2") {
                     timings.part_2 = Some(timing_str.into());
+                    timings.part_2_nanos = Some(nanos);
+                    timings.part_2_samples = samples;
+                    timings.part_2_median_nanos = median;
+                } else if part.contains("Parse") {
+                    // parsing is reported separately and not part of the total.
+                    timings.parse = Some(timing_str.into());
+                    return;
                 }
 
                 timings.total_nanos += nanos;
             });
 
+        timings.part_1_answer = parse_answer(output, 1);
+        timings.part_2_answer = parse_answer(output, 2);
+
         timings
     }
 
+    /// Extracts the answer to a part from the output of a solution.
+    /// Multi-line answers are printed below a `▼` marker and are joined with newlines.
+    pub(crate) fn parse_answer(output: &[String], part: u8) -> Option<String> {
+        let prefix = format!("Part {part}: ");
+        let index = output.iter().position(|l| l.starts_with(&prefix))?;
+        // benched parts overwrite their intermediate output with a carriage return.
+        let line = output[index].rsplit('\r').next()?.strip_prefix(&prefix)?;
+
+        if line.starts_with('✖') {
+            return None;
+        }
+
+        if line.starts_with('▼') {
+            let answer: Vec<&str> = output[index + 1..]
+                .iter()
+                .take_while(|l| {
+                    !l.starts_with("Part ") && !l.starts_with("Memory: ") && !l.is_empty()
+                })
+                .map(String::as_str)
+                .collect();
+            return Some(answer.join("\n"));
+        }
+
+        // strip the timing suffix as well as the ansi codes around the result.
+        let answer = line.rfind(" (").map_or(line, |i| &line[..i]);
+        let answer = answer
+            .trim_start_matches(ANSI_BOLD)
+            .trim_end_matches(ANSI_RESET);
+        Some(answer.to_string())
+    }
+
+    fn parse_median(line: &str) -> Option<f64> {
+        let median = line.split(", median ").nth(1)?.split(')').next()?;
+        parse_time(&format!("({median} @ 1 samples)")).map(|(_, nanos)| nanos)
+    }
+
+    fn parse_memory(line: &str) -> Option<&str> {
+        line.strip_prefix("Memory: ")?
+            .strip_suffix(" (peak)")
+            .map(str::trim)
+    }
+
+    fn parse_samples(line: &str) -> Option<u128> {
+        line.split(" samples")
+            .next()?
+            .split('@')
+            .next_back()?
+            .trim()
+            .parse()
+            .ok()
+    }
+
     fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
         s.split(postfix).next()?.parse().ok()
     }
@@ -168,10 +275,10 @@ mod child_commands {
     fn parse_time(line: &str) -> Option<(&str, f64)> {
         // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
         let str_timing = line
-            .split(" samples)")
+            .split(" samples")
             .next()?
             .split('(')
-            .last()?
+            .next_back()?
             .split('@')
             .next()?
             .trim();
@@ -202,7 +309,7 @@ mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_answer, parse_exec_time};
 
         use crate::day;
 
@@ -250,5 +357,59 @@ mod child_commands {
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn test_parse_and_memory() {
+            let res = parse_exec_time(
+                &[
+                    "Parse: ✔ (1.5ms @ 640 samples)".into(),
+                    "Part 1: 0 (2ms @ 500 samples)".into(),
+                    "Part 2: 10 (3ms @ 300 samples)".into(),
+                    "Memory: 2.4 MiB (peak)".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 5000000_f64);
+            assert_eq!(res.parse.unwrap(), "1.5ms");
+            assert_eq!(res.memory.unwrap(), "2.4 MiB");
+            assert_eq!(res.part_1_samples, Some(500));
+            assert_eq!(res.part_2_samples, Some(300));
+            assert_approx_eq!(res.part_2_nanos.unwrap(), 3000000_f64);
+        }
+
+        #[test]
+        fn test_median() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: \x1b[1m42\x1b[0m (2.5ms @ 500 samples, median 2.1ms)".into(),
+                    "Part 2: 10 (3ms @ 300 samples)".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 5500000_f64);
+            assert_eq!(res.part_1.unwrap(), "2.5ms");
+            assert_approx_eq!(res.part_1_median_nanos.unwrap(), 2100000_f64);
+            assert_eq!(res.part_1_samples, Some(500));
+            assert_eq!(res.part_2_median_nanos, None);
+            assert_eq!(res.part_1_answer.unwrap(), "42");
+            assert_eq!(res.part_2_answer.unwrap(), "10");
+        }
+
+        #[test]
+        fn test_answers() {
+            let output: Vec<String> = [
+                "Part 1: 1 > benching\rPart 1: \x1b[1m(1, 2)\x1b[0m (1.2µs @ 10 samples)",
+                "Part 2: ▼  (3.0ms)",
+                "#.#",
+                ".#.",
+                "",
+            ]
+            .map(Into::into)
+            .to_vec();
+            assert_eq!(parse_answer(&output, 1).unwrap(), "(1, 2)");
+            assert_eq!(parse_answer(&output, 2).unwrap(), "#.#\n.#.");
+            assert_eq!(parse_answer(&["Part 1: ✖   ".into()], 1), None);
+            assert_eq!(parse_answer(&[], 2), None);
+        }
     }
 }
@@ -0,0 +1,131 @@
+use std::{process, str::FromStr, time::SystemTime};
+
+use crate::template::session::{self, Session};
+use crate::template::{datetime, ANSI_BOLD, ANSI_RESET};
+
+pub enum Action {
+    Set,
+    Show,
+    Check,
+}
+
+impl FromStr for Action {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "set" => Ok(Action::Set),
+            "show" => Ok(Action::Show),
+            "check" => Ok(Action::Check),
+            _ => Err(format!(
+                "unknown auth command `{s}`, expecting set, show or check"
+            )),
+        }
+    }
+}
+
+fn load_or_exit() -> Session {
+    match session::load() {
+        Ok(session) => session,
+        Err(e) => {
+            eprintln!("Failed to load session: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+fn format_expiry(session: &Session) -> String {
+    let date = datetime::format_utc(datetime::from_unix_seconds(session.expires_at));
+    if session.expiry_known {
+        date
+    } else {
+        format!("{date} (estimated)")
+    }
+}
+
+fn set(token: Option<String>, expires: Option<String>) {
+    let Some(token) = token else {
+        eprintln!("No token given. Format: cargo auth set <token> [--expires YYYY-MM-DD]");
+        process::exit(1);
+    };
+
+    let expires_at = expires.map(|date| {
+        datetime::parse_date(&date).unwrap_or_else(|| {
+            eprintln!("Invalid expiry date `{date}`, expecting YYYY-MM-DD.");
+            process::exit(1);
+        })
+    });
+
+    let session = Session::new(&token, expires_at);
+
+    if !session.token.chars().all(|c| c.is_ascii_hexdigit()) {
+        eprintln!("Warning: session tokens usually only contain hexadecimal characters.");
+    }
+
+    match session::save(&session) {
+        Ok(path) => {
+            println!("Stored session token in \"{}\".", path.display());
+            println!("The token expires on {}.", format_expiry(&session));
+        }
+        Err(e) => {
+            eprintln!("Failed to store session token: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+fn show() {
+    let session = load_or_exit();
+    let now = datetime::unix_seconds(SystemTime::now());
+
+    if let Ok(path) = session::get_session_path() {
+        println!("{ANSI_BOLD}File:{ANSI_RESET}    {}", path.display());
+    }
+    println!("{ANSI_BOLD}Token:{ANSI_RESET}   {}", session.masked_token());
+    println!(
+        "{ANSI_BOLD}Set:{ANSI_RESET}     {}",
+        datetime::format_utc(datetime::from_unix_seconds(session.set_at))
+    );
+    println!(
+        "{ANSI_BOLD}Expires:{ANSI_RESET} {}",
+        format_expiry(&session)
+    );
+
+    if session.is_expired(now) && session.expiry_known {
+        println!("The token has expired. Run `cargo auth set <token>` to store a new one.");
+    } else if session.is_expired(now) {
+        println!("The token may have expired. Run `cargo auth check` to check it.");
+    }
+}
+
+fn check() {
+    let session = load_or_exit();
+    let now = datetime::unix_seconds(SystemTime::now());
+
+    match session::validate(&session) {
+        Ok(true) => {
+            println!("✔ The session token is valid.");
+            if session.is_expired(now) {
+                println!("The stored expiry date has passed, run `cargo auth set <token> --expires YYYY-MM-DD` to correct it.");
+            } else {
+                println!("It expires on {}.", format_expiry(&session));
+            }
+        }
+        Ok(false) => {
+            eprintln!("✖ The session token was rejected. Run `cargo auth set <token>` to store a new one.");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to check session token: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+pub fn handle(action: Action, token: Option<String>, expires: Option<String>) {
+    match action {
+        Action::Set => set(token, expires),
+        Action::Show => show(),
+        Action::Check => check(),
+    }
+}
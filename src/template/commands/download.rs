@@ -1,15 +1,145 @@
-use crate::template::aoc_cli;
+use crate::template::aoc_cli::{self, AocCommandError, DownloadTarget};
+use crate::template::cache::{Cache, FileState, Kind};
+use crate::template::{datetime, puzzle, session, unlock};
 use crate::Day;
-use std::process;
+use std::{fs, process, time::SystemTime};
 
-pub fn handle(day: Day) {
+/// Seconds to wait before fetching a puzzle description without part two again.
+const PUZZLE_REFRESH_INTERVAL: i64 = 15 * 60;
+
+/// Inputs never change once downloaded, they are only fetched if missing or empty.
+/// Local edits are kept, but reported. Inputs from before the cache existed are recorded as they are.
+fn should_fetch_input(cache: &mut Cache, day: Day) -> bool {
+    let path = Kind::Input.get_path(day);
+    let contents = fs::read(&path).ok();
+
+    match cache.state(Kind::Input, day, contents.as_deref()) {
+        FileState::Missing => true,
+        FileState::Untracked => match contents {
+            Some(contents) if !contents.is_empty() => {
+                cache.record(Kind::Input, day, &contents);
+                false
+            }
+            _ => true,
+        },
+        FileState::Unchanged(_) => false,
+        FileState::Modified(entry) => {
+            eprintln!(
+                "Warning: input file \"{path}\" was edited after it was downloaded on {}. Keeping local changes, append `--force` to download it again.",
+                datetime::format_utc(datetime::from_unix_seconds(entry.fetched_at))
+            );
+            false
+        }
+    }
+}
+
+/// Puzzle descriptions gain the text of part two once part one is solved, they are fetched until they contain it.
+/// Until then, a recent download is reused, so running the command again does not hit the site every time.
+fn should_fetch_puzzle(cache: &Cache, day: Day) -> bool {
+    let Ok(md) = puzzle::read(day) else {
+        return true;
+    };
+
+    if puzzle::part_blocks(puzzle::blocks(&md), 2).is_some() {
+        return false;
+    }
+
+    let now = datetime::unix_seconds(SystemTime::now());
+    !cache
+        .get(Kind::Puzzle, day)
+        .is_some_and(|entry| is_recent(entry.fetched_at, now))
+}
+
+fn is_recent(fetched_at: i64, now: i64) -> bool {
+    now - fetched_at < PUZZLE_REFRESH_INTERVAL
+}
+
+fn record(cache: &mut Cache, kind: Kind, day: Day) {
+    match fs::read(kind.get_path(day)) {
+        Ok(contents) => cache.record(kind, day, &contents),
+        Err(e) => eprintln!("Failed to read \"{}\": {e}", kind.get_path(day)),
+    }
+}
+
+fn save(cache: &Cache) {
+    if let Err(e) = cache.save() {
+        eprintln!("Failed to update download cache: {e}");
+    }
+}
+
+/// Downloads the input and puzzle description for a day, skipping files that are up to date.
+pub fn fetch(day: Day, wait: bool, force: bool) -> Result<(), AocCommandError> {
+    let mut cache = Cache::load().unwrap_or_else(|e| {
+        eprintln!("Failed to read download cache, ignoring it: {e}");
+        Cache::default()
+    });
+
+    let fetch_input = force || should_fetch_input(&mut cache, day);
+    let fetch_puzzle = force || should_fetch_puzzle(&cache, day);
+
+    let target = match (fetch_input, fetch_puzzle) {
+        (true, true) => DownloadTarget::All,
+        (true, false) => DownloadTarget::InputOnly,
+        (false, true) => DownloadTarget::PuzzleOnly,
+        (false, false) => {
+            println!("🎄 Input and puzzle for day {day} are up to date, nothing to download.");
+            save(&cache);
+            return Ok(());
+        }
+    };
+
+    if wait {
+        unlock::download_when_unlocked(day, target)?;
+    } else {
+        aoc_cli::download(day, target)?;
+    }
+
+    if fetch_input {
+        record(&mut cache, Kind::Input, day);
+    }
+    if fetch_puzzle {
+        record(&mut cache, Kind::Puzzle, day);
+    }
+
+    save(&cache);
+    Ok(())
+}
+
+pub fn handle(day: Day, wait: bool, force: bool) {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
+    if let Err(e) = session::ensure_usable() {
+        eprintln!("Cannot download: {e}");
+        process::exit(1);
+    }
+
+    if !wait {
+        if let Some(remaining) = unlock::time_until_unlock(day) {
+            eprintln!(
+                "Day {day} has not unlocked yet, it unlocks in {}. Append `--wait` to wait for it.",
+                datetime::format_countdown(remaining)
+            );
+            process::exit(1);
+        }
+    }
+
+    if let Err(e) = fetch(day, wait, force) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{is_recent, PUZZLE_REFRESH_INTERVAL};
+
+    #[test]
+    fn refreshes_puzzles_after_interval() {
+        let fetched_at = 1_701_406_800;
+        assert!(is_recent(fetched_at, fetched_at + 60));
+        assert!(!is_recent(fetched_at, fetched_at + PUZZLE_REFRESH_INTERVAL));
+    }
+}
@@ -0,0 +1,85 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{datetime, http, leaderboard, session, unlock};
+use crate::Day;
+
+/// The site asks to not request leaderboards more often than every 15 minutes.
+const MAX_CACHE_AGE: Duration = Duration::from_secs(15 * 60);
+
+fn get_cache_path(id: &str) -> PathBuf {
+    Path::new("data")
+        .join("leaderboards")
+        .join(format!("{id}.json"))
+}
+
+fn is_fresh(path: &Path) -> bool {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < MAX_CACHE_AGE)
+}
+
+fn fetch(id: &str) -> Result<String, String> {
+    let path = get_cache_path(id);
+
+    if is_fresh(&path) {
+        println!("Using cached leaderboard \"{}\".", path.display());
+        return fs::read_to_string(&path).map_err(|e| e.to_string());
+    }
+
+    let token = session::find_token().map_err(|e| e.to_string())?;
+    let year = unlock::get_year();
+
+    let response = http::get(
+        &format!("/{year}/leaderboard/private/view/{id}.json"),
+        Some(&token),
+    )
+    .map_err(|e| e.to_string())?;
+
+    if !response.is_success() {
+        return Err(format!(
+            "the site responded with status {}. Check the leaderboard id and run `cargo auth check` to verify your session token.",
+            response.status
+        ));
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    fs::write(&path, &response.body).map_err(|e| e.to_string())?;
+
+    Ok(response.body)
+}
+
+pub fn handle(id: Option<String>, file: Option<String>, day: Option<Day>) {
+    let result = match (file, id) {
+        (Some(file), _) => fs::read_to_string(&file).map_err(|e| format!("{file}: {e}")),
+        (None, Some(id)) => fetch(&id),
+        (None, None) => {
+            eprintln!("No leaderboard given. Format: cargo leaderboard <id> or cargo leaderboard --file <path>");
+            process::exit(1);
+        }
+    };
+
+    let board = match result.and_then(|s| leaderboard::parse(&s).map_err(|e| e.to_string())) {
+        Ok(board) => board,
+        Err(e) => {
+            eprintln!("Failed to load leaderboard: {e}");
+            process::exit(1);
+        }
+    };
+
+    println!("{}", leaderboard::render_overview(&board));
+
+    if let Some(day) = day.or_else(|| leaderboard::latest_day(&board)) {
+        let unlocked_at = datetime::unix_seconds(unlock::unlock_time(board.event, day));
+        println!();
+        println!("{}", leaderboard::render_day(&board, day, unlocked_at));
+    }
+}
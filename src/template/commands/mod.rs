@@ -1,5 +1,12 @@
 pub mod all;
+pub mod auth;
 pub mod download;
+pub mod leaderboard;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod stars;
+pub mod start;
+pub mod status;
+pub mod test;
+pub mod watch;
@@ -1,16 +1,40 @@
-use std::process;
+use std::{env, process};
 
-use crate::template::aoc_cli;
+use crate::template::{markdown, puzzle};
 use crate::Day;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+fn get_width() -> usize {
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|x| x.parse().ok())
+        .unwrap_or(markdown::DEFAULT_WIDTH)
+}
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+pub fn handle(day: Day, part: Option<u8>) {
+    let Ok(md) = puzzle::read(day) else {
+        eprintln!(
+            "Could not read puzzle description \"{}\". Run `cargo download {day}` to fetch it.",
+            puzzle::get_puzzle_path(day)
+        );
         process::exit(1);
     };
+
+    let blocks = puzzle::blocks(&md);
+
+    let blocks = match part {
+        None => blocks,
+        Some(part) => match puzzle::part_blocks(blocks, part) {
+            Some(blocks) => blocks,
+            None if part == 2 => {
+                eprintln!("Part two is not available yet. Solve part one, then run `cargo download {day}` again.");
+                process::exit(1);
+            }
+            None => {
+                eprintln!("Unknown part {part}, expecting 1 or 2.");
+                process::exit(1);
+            }
+        },
+    };
+
+    println!("{}", markdown::render(&blocks, get_width()));
 }
@@ -1,6 +1,7 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
@@ -34,61 +35,165 @@ mod tests {
 }
 "#;
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// A file that is written by the scaffold command.
+struct ScaffoldFile {
+    label: &'static str,
+    path: String,
+    contents: String,
+    /// protected files are never overwritten once they have contents, not even with `--force`.
+    protected: bool,
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// What happens to a scaffold file, given its current state on disk.
+#[derive(Debug, PartialEq, Eq)]
+enum Action {
+    Create,
+    Keep,
+    Protect,
+    Overwrite { backup: bool },
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Decides what to do with a file. `existing_len` is the size of the file if it exists already.
+fn plan(existing_len: Option<u64>, protected: bool, force: bool) -> Action {
+    match existing_len {
+        None => Action::Create,
+        Some(len) if protected && len > 0 => Action::Protect,
+        Some(len) if force && !protected => Action::Overwrite { backup: len > 0 },
+        Some(_) => Action::Keep,
+    }
+}
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
-    };
-
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
-        }
+/// Finds a free backup path for a file, e.g. `src/bin/01.rs.bak` or `src/bin/01.rs.bak.1`.
+fn backup_path(path: &str) -> String {
+    let mut candidate = format!("{path}.bak");
+    let mut i = 1;
+    while Path::new(&candidate).exists() {
+        candidate = format!("{path}.bak.{i}");
+        i += 1;
     }
+    candidate
+}
+
+fn write_new_file(path: &str, contents: &str) -> Result<(), std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)?
+        .write_all(contents.as_bytes())
+}
+
+fn apply(file: &ScaffoldFile, action: &Action, dry_run: bool) -> Result<(), std::io::Error> {
+    let prefix = if dry_run { "Would " } else { "" };
+    let ScaffoldFile { label, path, contents, .. } = file;
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    match action {
+        Action::Create => {
+            if !dry_run {
+                write_new_file(path, contents)?;
+            }
+            let verb = if dry_run { "create" } else { "Created" };
+            let empty = if contents.is_empty() { "empty " } else { "" };
+            println!("{prefix}{verb} {empty}{label} \"{path}\"");
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+        Action::Keep => {
+            println!("Skipping {label} \"{path}\": file already exists.");
+        }
+        Action::Protect => {
+            println!("Skipping {label} \"{path}\": file has contents and is never overwritten.");
+        }
+        Action::Overwrite { backup } => {
+            if *backup {
+                let backup_path = backup_path(path);
+                if !dry_run {
+                    fs::copy(path, &backup_path)?;
+                }
+                let verb = if dry_run { "back up" } else { "Backed up" };
+                println!("{prefix}{verb} \"{path}\" to \"{backup_path}\"");
+            }
+            if !dry_run {
+                fs::write(path, contents)?;
+            }
+            let verb = if dry_run { "overwrite" } else { "Overwrote" };
+            println!("{prefix}{verb} {label} \"{path}\"");
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+    Ok(())
+}
+
+pub fn handle(day: Day, force: bool, dry_run: bool) {
+    let files = [
+        ScaffoldFile {
+            label: "module file",
+            path: format!("src/bin/{day}.rs"),
+            contents: MODULE_TEMPLATE.replace("DAY_NUMBER", &day.into_inner().to_string()),
+            protected: false,
+        },
+        ScaffoldFile {
+            label: "input file",
+            path: format!("data/inputs/{day}.txt"),
+            contents: String::new(),
+            protected: true,
+        },
+        ScaffoldFile {
+            label: "example file",
+            path: format!("data/examples/{day}.txt"),
+            contents: String::new(),
+            protected: false,
+        },
+    ];
+
+    for file in &files {
+        let existing_len = fs::metadata(&file.path).ok().map(|m| m.len());
+        let action = plan(existing_len, file.protected, force);
+
+        if let Err(e) = apply(file, &action, dry_run) {
+            eprintln!("Failed to write {} \"{}\": {e}", file.label, file.path);
             process::exit(1);
         }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    if dry_run {
+        println!("🎄 Dry run, no files were written.");
+    } else {
+        println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{plan, Action};
+
+    #[test]
+    fn creates_missing_files() {
+        assert_eq!(plan(None, false, false), Action::Create);
+        assert_eq!(plan(None, true, true), Action::Create);
+    }
+
+    #[test]
+    fn keeps_existing_files_without_force() {
+        assert_eq!(plan(Some(0), false, false), Action::Keep);
+        assert_eq!(plan(Some(120), false, false), Action::Keep);
+        assert_eq!(plan(Some(0), true, false), Action::Keep);
+    }
+
+    #[test]
+    fn never_overwrites_protected_files_with_contents() {
+        assert_eq!(plan(Some(120), true, false), Action::Protect);
+        assert_eq!(plan(Some(120), true, true), Action::Protect);
+        assert_eq!(plan(Some(0), true, true), Action::Keep);
+    }
+
+    #[test]
+    fn overwrites_with_backup_when_forced() {
+        assert_eq!(
+            plan(Some(120), false, true),
+            Action::Overwrite { backup: true }
+        );
+        assert_eq!(
+            plan(Some(0), false, true),
+            Action::Overwrite { backup: false }
+        );
+    }
 }
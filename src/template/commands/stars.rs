@@ -0,0 +1,39 @@
+use std::process;
+
+use crate::template::stars::{self, Stars};
+use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+pub fn handle(sync: bool) {
+    let mut stars = match Stars::load() {
+        Ok(stars) => stars,
+        Err(e) => {
+            eprintln!("Failed to read recorded stars: {e}");
+            process::exit(1);
+        }
+    };
+
+    if sync {
+        match stars::sync(&mut stars) {
+            Ok(added) => println!("Synced progress from the site, found {added} new star(s)."),
+            Err(e) => {
+                eprintln!("Failed to sync progress: {e}");
+                process::exit(1);
+            }
+        }
+
+        if let Err(e) = stars.save() {
+            eprintln!("Failed to save recorded stars: {e}");
+            process::exit(1);
+        }
+    }
+
+    println!("{ANSI_BOLD}Stars:{ANSI_RESET} {} ⭐", stars.count());
+
+    match stars::update_readme(&stars) {
+        Ok(()) => println!("Successfully updated README with stars."),
+        Err(e) => {
+            eprintln!("Failed to update readme with stars: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::{fs, process};
+
+use crate::template::commands::{download, scaffold};
+use crate::template::{aoc_cli, puzzle, session, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::Day;
+
+fn write_example(day: Day, md: &str) {
+    let example_path = format!("data/examples/{day}.txt");
+
+    let is_empty = fs::metadata(&example_path).map_or(true, |m| m.len() == 0);
+    if !is_empty {
+        println!("Skipping example file \"{example_path}\": file already has contents.");
+        return;
+    }
+
+    let Some(example) = puzzle::example(md) else {
+        println!("Could not find an example in the puzzle description.");
+        return;
+    };
+
+    match fs::write(&example_path, format!("{example}\n")) {
+        Ok(()) => println!("Wrote example to \"{example_path}\""),
+        Err(e) => eprintln!("Failed to write example file: {e}"),
+    }
+}
+
+pub fn handle(day: Day, wait: bool) {
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    if let Err(e) = session::ensure_usable() {
+        eprintln!("Cannot download: {e}");
+        process::exit(1);
+    }
+
+    scaffold::handle(day, false, false);
+    println!();
+
+    if let Err(e) = download::fetch(day, wait, false) {
+        eprintln!("failed to call aoc-cli: {e}");
+        process::exit(1);
+    };
+
+    let md = match puzzle::read(day) {
+        Ok(md) => md,
+        Err(e) => {
+            eprintln!("Failed to read puzzle description: {e}");
+            process::exit(1);
+        }
+    };
+
+    write_example(day, &md);
+
+    println!();
+    if let Some(title) = puzzle::title(&md) {
+        println!("{ANSI_BOLD}{title}{ANSI_RESET}");
+    }
+    if let Some(paragraph) = puzzle::first_paragraph(&md) {
+        println!("{ANSI_ITALIC}{paragraph}{ANSI_RESET}");
+    }
+
+    println!("---");
+    println!("🎄 Type `cargo read {day}` to read the puzzle and `cargo test --bin {day}` to test your solution.");
+}
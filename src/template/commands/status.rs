@@ -0,0 +1,271 @@
+use std::{
+    fs,
+    path::Path,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+use crate::template::commands::all::get_path_for_bin;
+use crate::template::commands::watch::is_data_file;
+use crate::template::last_run::LastRun;
+use crate::template::stars::Stars;
+use crate::template::{
+    ANSI_BOLD, ANSI_DIM, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET, ANSI_YELLOW,
+};
+use crate::{all_days, Day};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Part {
+    Accepted,
+    Answered,
+    Unsolved,
+}
+
+#[derive(Debug)]
+struct DayStatus {
+    day: Day,
+    bin: bool,
+    input: bool,
+    example: bool,
+    /// [`None`] if the day has not been scaffolded.
+    tests: Option<bool>,
+    parts: [Part; 2],
+    bench_nanos: Option<f64>,
+}
+
+pub fn handle() {
+    let stars = Stars::load().unwrap_or_else(|e| {
+        eprintln!("Failed to read recorded stars: {e}");
+        Stars::default()
+    });
+
+    let last_run = LastRun::load().unwrap_or_else(|e| {
+        eprintln!("Failed to read results of the last run: {e}");
+        LastRun::default()
+    });
+
+    let scaffolded: Vec<Day> = all_days()
+        .filter(|&day| Path::new(&get_path_for_bin(day)).exists())
+        .collect();
+
+    if !scaffolded.is_empty() {
+        println!("{ANSI_ITALIC}Running tests...{ANSI_RESET}");
+        // build all test binaries up front, so that testing the days one by one is fast.
+        let _ = Command::new("cargo")
+            .args(["test", "--quiet", "--bins", "--no-run"])
+            .stdout(Stdio::null())
+            .status();
+    }
+
+    let rows: Vec<DayStatus> = all_days()
+        .map(|day| {
+            let tests = scaffolded.contains(&day).then(|| run_tests(day));
+            get_status(day, tests, &stars, &last_run)
+        })
+        .collect();
+
+    println!("{}", render(&rows));
+}
+
+fn run_tests(day: Day) -> bool {
+    Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &day.to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+fn is_non_empty(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+/// Whether there is a non-empty example for `day`, either `NN.txt` or a part file like `NN-a.txt`.
+fn has_example(day: Day) -> bool {
+    let Ok(entries) = fs::read_dir("data/examples") else {
+        return false;
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .any(|path| is_data_file(&path, day) && is_non_empty(&path))
+}
+
+fn get_status(day: Day, tests: Option<bool>, stars: &Stars, last_run: &LastRun) -> DayStatus {
+    let entry = last_run.get(day);
+    let answers = [
+        entry.and_then(|e| e.part_1.as_ref()),
+        entry.and_then(|e| e.part_2.as_ref()),
+    ];
+
+    let parts = [1, 2].map(|part| {
+        if stars.has(day, part) {
+            Part::Accepted
+        } else if answers[usize::from(part - 1)].is_some() {
+            Part::Answered
+        } else {
+            Part::Unsolved
+        }
+    });
+
+    DayStatus {
+        day,
+        bin: Path::new(&get_path_for_bin(day)).exists(),
+        input: is_non_empty(Path::new(&format!("data/inputs/{day}.txt"))),
+        example: has_example(day),
+        tests,
+        parts,
+        bench_nanos: entry.and_then(|e| e.total_nanos),
+    }
+}
+
+/// Pads `text` to `width` visible characters, then applies `color`.
+fn cell(text: &str, color: &str, width: usize) -> String {
+    format!("{color}{text:^width$}{ANSI_RESET}")
+}
+
+fn check(ok: bool, width: usize) -> String {
+    if ok {
+        cell("✔", ANSI_GREEN, width)
+    } else {
+        cell("✖", ANSI_RED, width)
+    }
+}
+
+fn format_part(part: Part) -> String {
+    match part {
+        Part::Accepted => cell("★", ANSI_YELLOW, 4),
+        Part::Answered => cell("✔", ANSI_GREEN, 4),
+        Part::Unsolved => cell("·", ANSI_DIM, 4),
+    }
+}
+
+fn format_bench(nanos: Option<f64>) -> String {
+    match nanos {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Some(nanos) => format!("{:.1?}", Duration::from_nanos(nanos as u64)),
+        None => format!("{ANSI_DIM}-{ANSI_RESET}"),
+    }
+}
+
+const HEADER: [(&str, usize); 7] = [
+    ("Day", 5),
+    ("Bin", 5),
+    ("Input", 7),
+    ("Example", 9),
+    ("Tests", 7),
+    ("P1", 4),
+    ("P2", 4),
+];
+
+fn render_row(status: &DayStatus) -> String {
+    let widths = HEADER.map(|(_, width)| width);
+
+    let cells = if status.bin {
+        [
+            check(status.input, widths[2]),
+            check(status.example, widths[3]),
+            status
+                .tests
+                .map_or_else(|| cell("·", ANSI_DIM, widths[4]), |ok| check(ok, widths[4])),
+            format_part(status.parts[0]),
+            format_part(status.parts[1]),
+        ]
+    } else {
+        // days that have not been scaffolded are dimmed out entirely.
+        [2, 3, 4, 5, 6].map(|i| cell("·", ANSI_DIM, widths[i]))
+    };
+
+    format!(
+        "{:^w0$}{}{}{}",
+        status.day.to_string(),
+        check(status.bin, widths[1]),
+        cells.join(""),
+        if status.bin {
+            format_bench(status.bench_nanos)
+        } else {
+            String::new()
+        },
+        w0 = widths[0]
+    )
+}
+
+fn render(rows: &[DayStatus]) -> String {
+    let header: String = HEADER
+        .iter()
+        .map(|(title, width)| format!("{title:^width$}"))
+        .collect();
+
+    let mut lines = vec![format!("{ANSI_BOLD}{header}Bench{ANSI_RESET}")];
+    lines.extend(rows.iter().map(render_row));
+
+    let count = |part: Part| {
+        rows.iter()
+            .flat_map(|r| r.parts)
+            .filter(|&p| p == part)
+            .count()
+    };
+    lines.push(String::new());
+    lines.push(format!(
+        "{ANSI_YELLOW}★{ANSI_RESET} accepted: {}  {ANSI_GREEN}✔{ANSI_RESET} answered: {}  {ANSI_DIM}·{ANSI_RESET} unsolved: {}",
+        count(Part::Accepted),
+        count(Part::Answered),
+        count(Part::Unsolved)
+    ));
+
+    lines.join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render_row, DayStatus, Part};
+    use crate::day;
+
+    fn strip_ansi(s: &str) -> String {
+        let mut out = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|&c| c == 'm');
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn renders_scaffolded_day() {
+        let status = DayStatus {
+            day: day!(3),
+            bin: true,
+            input: true,
+            example: false,
+            tests: Some(true),
+            parts: [Part::Accepted, Part::Answered],
+            bench_nanos: Some(1_500_000_f64),
+        };
+        assert_eq!(
+            strip_ansi(&render_row(&status)),
+            " 03    ✔     ✔       ✖       ✔    ★   ✔  1.5ms"
+        );
+    }
+
+    #[test]
+    fn dims_days_without_solution() {
+        let status = DayStatus {
+            day: day!(12),
+            bin: false,
+            input: true,
+            example: true,
+            tests: None,
+            parts: [Part::Unsolved, Part::Unsolved],
+            bench_nanos: None,
+        };
+        assert_eq!(
+            strip_ansi(&render_row(&status)),
+            " 12    ✖     ·       ·       ·    ·   ·  "
+        );
+    }
+}
@@ -0,0 +1,336 @@
+use std::{
+    path::Path,
+    process::{self, Command, Stdio},
+};
+
+use crate::template::commands::all::get_path_for_bin;
+use crate::template::{ANSI_BOLD, ANSI_DIM, ANSI_GREEN, ANSI_ITALIC, ANSI_RED, ANSI_RESET};
+use crate::{all_days, Day};
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Failure {
+    name: String,
+    file: Option<String>,
+    expected: Option<String>,
+    actual: Option<String>,
+    /// The panic message, for failures that do not compare answers.
+    message: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Report {
+    passed: usize,
+    failures: Vec<Failure>,
+    /// Whether cargo ran the tests at all, i.e. the solution compiled.
+    ran: bool,
+}
+
+/// Parses the days to test: a single day (`8`), an inclusive range (`1..5` or `1-5`) or `all`.
+pub fn parse_days(s: &str) -> Result<Vec<Day>, String> {
+    if s == "all" {
+        return Ok(all_days().collect());
+    }
+
+    let parse = |s: &str| {
+        s.trim()
+            .parse::<Day>()
+            .map_err(|_| format!("invalid day `{s}`, expecting a number between 1 and 25"))
+    };
+
+    let Some((start, end)) = s.split_once("..").or_else(|| s.split_once('-')) else {
+        return Ok(vec![parse(s)?]);
+    };
+
+    let (start, end) = (parse(start)?, parse(end)?);
+    if start > end {
+        return Err(format!(
+            "invalid range `{s}`, the first day must come first"
+        ));
+    }
+
+    Ok(all_days().filter(|&d| d >= start && d <= end).collect())
+}
+
+pub fn handle(days: Option<Vec<Day>>) {
+    let days: Vec<Day> = days
+        .unwrap_or_else(|| all_days().collect())
+        .into_iter()
+        .filter(|&day| Path::new(&get_path_for_bin(day)).exists())
+        .collect();
+
+    if days.is_empty() {
+        eprintln!("No scaffolded days to test.");
+        process::exit(1);
+    }
+
+    if days.len() > 1 {
+        println!("{ANSI_ITALIC}Building tests...{ANSI_RESET}");
+        // build all test binaries up front, instead of once per day.
+        let _ = Command::new("cargo")
+            .args(["test", "--quiet", "--bins", "--no-run"])
+            .stdout(Stdio::null())
+            .status();
+    }
+
+    let reports: Vec<(Day, Report)> = days.into_iter().map(|day| (day, run_tests(day))).collect();
+
+    println!("{}", render_table(&reports));
+
+    let failures = render_failures(&reports);
+    if !failures.is_empty() {
+        println!("\n{failures}");
+    }
+
+    if reports
+        .iter()
+        .any(|(_, r)| !r.ran || !r.failures.is_empty())
+    {
+        process::exit(1);
+    }
+}
+
+fn run_tests(day: Day) -> Report {
+    let output = Command::new("cargo")
+        .args(["test", "--bin", &day.to_string()])
+        .env("RUST_BACKTRACE", "0")
+        .stderr(Stdio::null())
+        .output();
+
+    match output {
+        Ok(output) => parse_output(&String::from_utf8_lossy(&output.stdout)),
+        Err(_) => Report::default(),
+    }
+}
+
+/// Extracts the answers from a failure message of a generated example test,
+/// e.g. `example data/examples/08-a.txt: expected 3, got 2`.
+fn parse_example_failure(failure: &mut Failure, line: &str) -> bool {
+    let Some((file, rest)) = line
+        .strip_prefix("example ")
+        .and_then(|l| l.split_once(": expected "))
+    else {
+        return false;
+    };
+
+    let Some((expected, actual)) = rest.rsplit_once(", got ") else {
+        return false;
+    };
+
+    failure.file = Some(file.into());
+    failure.expected = Some(expected.into());
+    failure.actual = Some(actual.into());
+    true
+}
+
+/// Parses the output of a failing test. Hand-written tests usually call `assert_eq!(result, expected)`.
+fn parse_failure(name: &str, lines: &[&str]) -> Failure {
+    let mut failure = Failure {
+        name: name.into(),
+        ..Failure::default()
+    };
+
+    let mut message = vec![];
+    let mut in_panic = false;
+
+    for line in lines {
+        if line.starts_with("thread '") && line.contains("panicked at") {
+            in_panic = true;
+            continue;
+        }
+
+        if !in_panic || line.starts_with("note: ") || line.is_empty() {
+            continue;
+        }
+
+        if parse_example_failure(&mut failure, line) {
+            return failure;
+        }
+
+        if let Some(left) = line.strip_prefix("  left: ") {
+            failure.actual = Some(left.into());
+        } else if let Some(right) = line.strip_prefix(" right: ") {
+            failure.expected = Some(right.into());
+        } else {
+            message.push(*line);
+        }
+    }
+
+    if !message.is_empty() {
+        failure.message = Some(message.join("\n"));
+    }
+
+    failure
+}
+
+fn parse_output(stdout: &str) -> Report {
+    let lines: Vec<&str> = stdout.lines().collect();
+    let mut report = Report::default();
+
+    for line in &lines {
+        if let Some(rest) = line.strip_prefix("test ") {
+            if rest.ends_with(" ... ok") {
+                report.passed += 1;
+            }
+        }
+
+        if line.starts_with("test result: ") {
+            report.ran = true;
+        }
+    }
+
+    // every failing test prints a section like `---- name stdout ----`.
+    let sections: Vec<(usize, &str)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| {
+            let name = l.strip_prefix("---- ")?.strip_suffix(" stdout ----")?;
+            Some((i, name))
+        })
+        .collect();
+
+    for (index, &(start, name)) in sections.iter().enumerate() {
+        let end = sections.get(index + 1).map_or_else(
+            || {
+                lines[start..]
+                    .iter()
+                    .position(|l| *l == "failures:")
+                    .map_or(lines.len(), |p| start + p)
+            },
+            |&(next, _)| next,
+        );
+        report
+            .failures
+            .push(parse_failure(name, &lines[start + 1..end]));
+    }
+
+    report
+}
+
+fn render_table(reports: &[(Day, Report)]) -> String {
+    let mut lines = vec![format!("{ANSI_BOLD} Day  Passed  Failed{ANSI_RESET}")];
+
+    for (day, report) in reports {
+        let status = if !report.ran {
+            format!("{ANSI_RED}✖ build failed{ANSI_RESET}")
+        } else if report.failures.is_empty() {
+            format!("{ANSI_GREEN}✔{ANSI_RESET}")
+        } else {
+            format!("{ANSI_RED}✖{ANSI_RESET}")
+        };
+
+        lines.push(format!(
+            " {:<5}{:^6}  {:^6}  {status}",
+            day.to_string(),
+            report.passed,
+            report.failures.len()
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn render_failures(reports: &[(Day, Report)]) -> String {
+    let mut lines = vec![];
+
+    for (day, report) in reports {
+        for failure in &report.failures {
+            lines.push(format!(
+                "{ANSI_BOLD}Day {day}{ANSI_RESET} · {}",
+                failure.name
+            ));
+
+            let fields = [
+                ("file", &failure.file),
+                ("expected", &failure.expected),
+                ("actual", &failure.actual),
+                ("message", &failure.message),
+            ];
+
+            for (label, value) in fields {
+                if let Some(value) = value {
+                    lines.push(format!("  {ANSI_DIM}{label:<9}{ANSI_RESET}{value}"));
+                }
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_days, parse_output, Failure};
+    use crate::day;
+
+    #[test]
+    fn parses_days() {
+        assert_eq!(parse_days("8").unwrap(), vec![day!(8)]);
+        assert_eq!(parse_days("3..5").unwrap(), vec![day!(3), day!(4), day!(5)]);
+        assert_eq!(parse_days("24-25").unwrap(), vec![day!(24), day!(25)]);
+        assert_eq!(parse_days("all").unwrap().len(), 25);
+        assert!(parse_days("5..3").is_err());
+        assert!(parse_days("26").is_err());
+    }
+
+    #[test]
+    fn parses_failing_tests() {
+        let stdout = "
+running 3 tests
+test example_tests::part_one_08_a ... FAILED
+test example_tests::part_one_08_b ... ok
+test tests::test_part_two ... FAILED
+
+failures:
+
+---- example_tests::part_one_08_a stdout ----
+
+thread 'example_tests::part_one_08_a' (32747) panicked at out/example_tests_8.rs:11:9:
+example data/examples/08-a.txt: expected 3, got 2
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+---- tests::test_part_two stdout ----
+
+thread 'tests::test_part_two' (32748) panicked at src/bin/08.rs:20:9:
+assertion `left == right` failed
+  left: Some(5)
+ right: Some(6)
+
+
+failures:
+    example_tests::part_one_08_a
+    tests::test_part_two
+
+test result: FAILED. 1 passed; 2 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+
+        let report = parse_output(stdout);
+        assert!(report.ran);
+        assert_eq!(report.passed, 1);
+        assert_eq!(
+            report.failures,
+            vec![
+                Failure {
+                    name: "example_tests::part_one_08_a".into(),
+                    file: Some("data/examples/08-a.txt".into()),
+                    expected: Some("3".into()),
+                    actual: Some("2".into()),
+                    message: None,
+                },
+                Failure {
+                    name: "tests::test_part_two".into(),
+                    file: None,
+                    expected: Some("Some(6)".into()),
+                    actual: Some("Some(5)".into()),
+                    message: Some("assertion `left == right` failed".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn detects_build_failures() {
+        let report = parse_output("");
+        assert!(!report.ran);
+        assert!(report.failures.is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::commands::all::{child_commands, get_path_for_bin};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::Day;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+type Answers = [Option<String>; 2];
+
+pub fn handle(day: Day) {
+    if !Path::new(&get_path_for_bin(day)).exists() {
+        eprintln!("Day {day} has not been scaffolded yet. Try running \"cargo scaffold {day}\".");
+        process::exit(1);
+    }
+
+    let mut snapshot = Snapshot::new();
+    let mut previous: Option<Answers> = None;
+
+    loop {
+        let current = take_snapshot(day);
+
+        if current != snapshot {
+            snapshot = current;
+            print!("{CLEAR_SCREEN}");
+            previous = Some(run(day, previous.as_ref()));
+            println!(
+                "\n{ANSI_ITALIC}Watching day {day} for changes. Press Ctrl+C to stop.{ANSI_RESET}"
+            );
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Runs the tests and the solution of a day and prints how the answers changed since the last run.
+fn run(day: Day, previous: Option<&Answers>) -> Answers {
+    println!("{ANSI_BOLD}Tests{ANSI_RESET}");
+    println!("------");
+
+    let tests_passed = Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &day.to_string()])
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .is_ok_and(|status| status.success());
+
+    println!("\n{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+
+    let output = child_commands::run_solution(day, false, false).unwrap_or_default();
+    let answers = [1, 2].map(|part| child_commands::parse_answer(&output, part));
+
+    println!("\n{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("------");
+    println!("Tests: {}", if tests_passed { "✔" } else { "✖" });
+    for line in diff_answers(previous, &answers) {
+        println!("{line}");
+    }
+
+    answers
+}
+
+/// Files that trigger a rerun: the solution, the library modules and the data files of the day.
+fn watched_files(day: Day) -> Vec<PathBuf> {
+    let mut files = vec![PathBuf::from(get_path_for_bin(day))];
+    collect_sources(Path::new("src"), &mut files);
+
+    for folder in ["data/inputs", "data/examples"] {
+        let Ok(entries) = fs::read_dir(folder) else {
+            continue;
+        };
+
+        files.extend(
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| is_data_file(path, day)),
+        );
+    }
+
+    files
+}
+
+/// Collects the rust files in `dir`, skipping the solutions of other days.
+fn collect_sources(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+        if path.is_dir() {
+            if path != Path::new("src/bin") {
+                collect_sources(&path, files);
+            }
+        } else if path.extension().is_some_and(|ext| ext == "rs")
+            && path != Path::new("src/main.rs")
+        {
+            files.push(path);
+        }
+    }
+}
+
+/// Whether `path` is a data file of `day`, e.g. `05.txt` or `05-2.txt`.
+#[must_use]
+pub fn is_data_file(path: &Path, day: Day) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+
+    let day = day.to_string();
+    name.strip_prefix(&day)
+        .and_then(|rest| rest.strip_suffix(".txt"))
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+}
+
+fn take_snapshot(day: Day) -> Snapshot {
+    watched_files(day)
+        .into_iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, modified))
+        })
+        .collect()
+}
+
+fn format_answer(answer: Option<&String>) -> String {
+    match answer {
+        // multi-line answers are too long for a compact summary.
+        Some(answer) if answer.contains('\n') => "▼".into(),
+        Some(answer) => answer.clone(),
+        None => "✖".into(),
+    }
+}
+
+/// Compares the answers of two runs, e.g. `Part 1: 42 → 43`.
+fn diff_answers(previous: Option<&Answers>, current: &Answers) -> Vec<String> {
+    current
+        .iter()
+        .enumerate()
+        .map(|(i, answer)| {
+            let part = i + 1;
+            let now = format_answer(answer.as_ref());
+
+            match previous.map(|p| &p[i]) {
+                None => format!("Part {part}: {now}"),
+                Some(before) if before == answer => format!("Part {part}: {now} (unchanged)"),
+                Some(before) => format!(
+                    "Part {part}: {} → {ANSI_BOLD}{now}{ANSI_RESET}",
+                    format_answer(before.as_ref())
+                ),
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+
+    use super::{diff_answers, is_data_file};
+    use crate::day;
+    use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+    #[test]
+    fn matches_data_files_of_day() {
+        assert!(is_data_file(Path::new("data/inputs/05.txt"), day!(5)));
+        assert!(is_data_file(Path::new("data/examples/05-2.txt"), day!(5)));
+        assert!(!is_data_file(Path::new("data/examples/15.txt"), day!(5)));
+        assert!(!is_data_file(Path::new("data/examples/050.txt"), day!(5)));
+        assert!(!is_data_file(Path::new("data/examples/05.toml"), day!(5)));
+    }
+
+    #[test]
+    fn diffs_answers() {
+        let current = [Some("42".to_string()), None];
+        assert_eq!(
+            diff_answers(None, &current),
+            vec!["Part 1: 42", "Part 2: ✖"]
+        );
+
+        let previous = [Some("41".to_string()), None];
+        assert_eq!(
+            diff_answers(Some(&previous), &current),
+            vec![
+                format!("Part 1: 41 → {ANSI_BOLD}42{ANSI_RESET}"),
+                "Part 2: ✖ (unchanged)".to_string()
+            ]
+        );
+    }
+}
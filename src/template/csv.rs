@@ -0,0 +1,149 @@
+/// Module that exports timings and answers gathered by `cargo all` to a CSV file.
+/// Each day and part gets one row, so progress can be tracked in a spreadsheet.
+use std::{fs, io, process::Command};
+
+use crate::template::readme_benchmarks::Timings;
+use crate::template::stars::Stars;
+
+static HEADER: &str = "day,part,answer,status,mean_ns,median_ns,samples,commit";
+
+/// Whether a part has been solved, and whether the answer was accepted by the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Accepted,
+    Answered,
+    Unsolved,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Accepted => "accepted",
+            Status::Answered => "answered",
+            Status::Unsolved => "unsolved",
+        }
+    }
+}
+
+/// Quotes a field if it contains characters that have a meaning in CSV.
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn format_nanos(nanos: Option<f64>) -> String {
+    nanos.map_or_else(String::new, |n| format!("{n:.0}"))
+}
+
+/// Renders one row per day and part. `commit` is repeated on every row to keep the file self-contained.
+#[must_use]
+pub fn render(timings: &[Timings], stars: &Stars, commit: &str) -> String {
+    let mut lines = vec![HEADER.to_string()];
+
+    for timing in timings {
+        let parts = [
+            (
+                &timing.part_1_answer,
+                timing.part_1_nanos,
+                timing.part_1_median_nanos,
+                timing.part_1_samples,
+            ),
+            (
+                &timing.part_2_answer,
+                timing.part_2_nanos,
+                timing.part_2_median_nanos,
+                timing.part_2_samples,
+            ),
+        ];
+
+        for (part, (answer, mean, median, samples)) in (1_u8..).zip(parts) {
+            let status = match answer {
+                Some(_) if stars.has(timing.day, part) => Status::Accepted,
+                Some(_) => Status::Answered,
+                None => Status::Unsolved,
+            };
+
+            lines.push(
+                [
+                    timing.day.to_string(),
+                    part.to_string(),
+                    escape(answer.as_deref().unwrap_or_default()),
+                    status.as_str().to_string(),
+                    format_nanos(mean),
+                    format_nanos(median),
+                    samples.map(|s| s.to_string()).unwrap_or_default(),
+                    escape(commit),
+                ]
+                .join(","),
+            );
+        }
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// Returns the short hash of the checked out commit, or an empty string outside of a git repository.
+#[must_use]
+pub fn get_commit() -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_default()
+}
+
+pub fn write(timings: &[Timings], stars: &Stars, path: &str) -> Result<(), io::Error> {
+    fs::write(path, render(timings, stars, &get_commit()))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{escape, render};
+    use crate::day;
+    use crate::template::readme_benchmarks::Timings;
+    use crate::template::stars::Stars;
+
+    #[test]
+    fn escapes_fields() {
+        assert_eq!(escape("123"), "123");
+        assert_eq!(escape("1,2"), "\"1,2\"");
+        assert_eq!(escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape("#.\n.#"), "\"#.\n.#\"");
+    }
+
+    #[test]
+    fn renders_rows_per_part() {
+        let mut stars = Stars::default();
+        stars.record(day!(1), 1);
+
+        let timings = vec![
+            Timings {
+                part_1_answer: Some("42".into()),
+                part_1_nanos: Some(2500.4),
+                part_1_median_nanos: Some(2100_f64),
+                part_1_samples: Some(500),
+                part_2_answer: Some("(1, 2)".into()),
+                ..Timings::new(day!(1))
+            },
+            Timings::new(day!(2)),
+        ];
+
+        let expected = [
+            "day,part,answer,status,mean_ns,median_ns,samples,commit",
+            "01,1,42,accepted,2500,2100,500,abc1234",
+            "01,2,\"(1, 2)\",answered,,,,abc1234",
+            "02,1,,unsolved,,,,abc1234",
+            "02,2,,unsolved,,,,abc1234",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(render(&timings, &stars, "abc1234"), expected);
+    }
+}
@@ -0,0 +1,154 @@
+/// Minimal calendar helpers for the UTC timestamps the template deals with.
+/// The algorithms are taken from: http://howardhinnant.github.io/date_algorithms.html
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Returns the number of days since 1970-01-01 for a date in the proleptic gregorian calendar.
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the `(year, month, day)` for a number of days since 1970-01-01.
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Seconds since the unix epoch for a point in time. Times before the epoch are negative.
+pub fn unix_seconds(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(d) => i64::try_from(d.as_secs()).unwrap_or(i64::MAX),
+        Err(e) => -i64::try_from(e.duration().as_secs()).unwrap_or(i64::MAX),
+    }
+}
+
+/// Converts seconds since the unix epoch into a [`SystemTime`].
+pub fn from_unix_seconds(seconds: i64) -> SystemTime {
+    if seconds >= 0 {
+        UNIX_EPOCH + Duration::from_secs(seconds.unsigned_abs())
+    } else {
+        UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs())
+    }
+}
+
+/// The current year in UTC.
+pub fn current_year() -> i64 {
+    civil_from_days(unix_seconds(SystemTime::now()).div_euclid(SECONDS_PER_DAY)).0
+}
+
+/// Formats a point in time as `YYYY-MM-DD HH:MM:SS UTC`.
+pub fn format_utc(time: SystemTime) -> String {
+    let seconds = unix_seconds(time);
+    let (year, month, day) = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY));
+    let time_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02} UTC",
+        time_of_day / 3600,
+        time_of_day % 3600 / 60,
+        time_of_day % 60
+    )
+}
+
+/// Parses a `YYYY-MM-DD` date into seconds since the unix epoch at midnight UTC.
+pub fn parse_date(s: &str) -> Option<i64> {
+    let mut parts = s.trim().splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts
+        .next()?
+        .parse()
+        .ok()
+        .filter(|m| (1..=12).contains(m))?;
+    let day = parts
+        .next()?
+        .parse()
+        .ok()
+        .filter(|d| (1..=31).contains(d))?;
+    Some(days_from_civil(year, month, day) * SECONDS_PER_DAY)
+}
+
+/// Formats a duration as a countdown, e.g. `1d 02:03:04` or `02:03:04`.
+pub fn format_countdown(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let days = seconds / 86_400;
+    let clock = format!(
+        "{:02}:{:02}:{:02}",
+        seconds % 86_400 / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    );
+
+    if days > 0 {
+        format!("{days}d {clock}")
+    } else {
+        clock
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        civil_from_days, days_from_civil, format_countdown, format_utc, from_unix_seconds,
+        parse_date,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn converts_dates_to_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_from_civil(2023, 12, 1), 19_692);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+    }
+
+    #[test]
+    fn converts_days_to_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_692), (2023, 12, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(days_from_civil(2024, 2, 29)), (2024, 2, 29));
+    }
+
+    #[test]
+    fn formats_utc_timestamps() {
+        assert_eq!(
+            format_utc(from_unix_seconds(1_701_406_800)),
+            "2023-12-01 05:00:00 UTC"
+        );
+    }
+
+    #[test]
+    fn parses_dates() {
+        assert_eq!(parse_date("2023-12-01"), Some(1_701_388_800));
+        assert_eq!(parse_date("2023-13-01"), None);
+        assert_eq!(parse_date("tomorrow"), None);
+    }
+
+    #[test]
+    fn formats_countdowns() {
+        assert_eq!(format_countdown(Duration::from_secs(59)), "00:00:59");
+        assert_eq!(
+            format_countdown(Duration::from_secs(3 * 3600 + 61)),
+            "03:01:01"
+        );
+        assert_eq!(format_countdown(Duration::from_secs(90_061)), "1d 01:01:01");
+    }
+}
@@ -0,0 +1,118 @@
+/// Wrapper module around the "curl" command-line, used for requests that aoc-cli does not cover.
+/// The base url can be pointed at a local stand-in for the site by setting `AOC_BASE_URL`.
+use std::{
+    fmt::Display,
+    io::Write,
+    process::{Command, Stdio},
+};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust (curl)";
+
+#[derive(Debug)]
+pub enum HttpError {
+    CommandNotFound,
+    BadExitStatus(String),
+    BadResponse,
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpError::CommandNotFound => write!(f, "curl is not present in environment."),
+            HttpError::BadExitStatus(e) => write!(f, "curl exited with a non-zero status: {e}"),
+            HttpError::BadResponse => write!(f, "could not read the response of the request."),
+        }
+    }
+}
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+#[must_use]
+pub fn get_base_url() -> String {
+    std::env::var("AOC_BASE_URL")
+        .unwrap_or_else(|_| DEFAULT_BASE_URL.into())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// Sends a GET request for `path` (relative to the base url), authenticated with a session token if given.
+/// Redirects are not followed, the site redirects unauthenticated requests to its login page.
+pub fn get(path: &str, session: Option<&str>) -> Result<Response, HttpError> {
+    let url = format!("{}{path}", get_base_url());
+
+    let mut cmd = Command::new("curl")
+        .args([
+            "--silent",
+            "--show-error",
+            "--max-time",
+            "30",
+            "--user-agent",
+            USER_AGENT,
+            // headers are read from stdin so the token does not show up in the process list.
+            "--header",
+            "@-",
+            "--write-out",
+            "\n%{http_code}",
+            &url,
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|_| HttpError::CommandNotFound)?;
+
+    if let Some(mut stdin) = cmd.stdin.take() {
+        if let Some(session) = session {
+            writeln!(stdin, "Cookie: session={session}").map_err(|_| HttpError::BadResponse)?;
+        }
+    }
+
+    let output = cmd.wait_with_output().map_err(|_| HttpError::BadResponse)?;
+
+    if !output.status.success() {
+        return Err(HttpError::BadExitStatus(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    parse_output(&String::from_utf8_lossy(&output.stdout)).ok_or(HttpError::BadResponse)
+}
+
+fn parse_output(stdout: &str) -> Option<Response> {
+    let (body, status) = stdout.rsplit_once('\n')?;
+    Some(Response {
+        status: status.trim().parse().ok()?,
+        body: body.to_string(),
+    })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_output;
+
+    #[test]
+    fn parses_status_and_body() {
+        let res = parse_output("{\"a\": 1}\n200").unwrap();
+        assert_eq!(res.status, 200);
+        assert_eq!(res.body, "{\"a\": 1}");
+        assert!(res.is_success());
+    }
+
+    #[test]
+    fn parses_empty_body() {
+        let res = parse_output("\n302").unwrap();
+        assert_eq!(res.status, 302);
+        assert_eq!(res.body, "");
+        assert!(!res.is_success());
+    }
+}
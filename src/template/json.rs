@@ -0,0 +1,319 @@
+/// A minimal JSON parser for the few JSON documents the template reads, e.g. private leaderboards.
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    /// Keys keep the order of the document.
+    Object(Vec<(String, Value)>),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Error {
+    pub position: usize,
+    pub message: String,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid JSON at position {}: {}",
+            self.position, self.message
+        )
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Value {
+    /// Returns the value of `key` if this is an object that contains it.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn as_i64(&self) -> Option<i64> {
+        self.as_f64().filter(|n| n.fract() == 0.0).map(|n| n as i64)
+    }
+
+    #[must_use]
+    pub fn as_object(&self) -> Option<&[(String, Value)]> {
+        match self {
+            Value::Object(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error<T>(&self, message: &str) -> Result<T, Error> {
+        Err(Error {
+            position: self.pos,
+            message: message.into(),
+        })
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, literal: &str) -> Result<(), Error> {
+        if self.bytes[self.pos..].starts_with(literal.as_bytes()) {
+            self.pos += literal.len();
+            Ok(())
+        } else {
+            self.error(&format!("expected `{literal}`"))
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, Error> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => self.string().map(Value::String),
+            Some(b't') => self.expect("true").map(|()| Value::Bool(true)),
+            Some(b'f') => self.expect("false").map(|()| Value::Bool(false)),
+            Some(b'n') => self.expect("null").map(|()| Value::Null),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(_) => self.error("unexpected character"),
+            None => self.error("unexpected end of input"),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, Error> {
+        self.expect("{")?;
+        let mut entries = vec![];
+
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Value::Object(entries));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(":")?;
+            entries.push((key, self.value()?));
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Value::Object(entries));
+                }
+                _ => return self.error("expected `,` or `}`"),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, Error> {
+        self.expect("[")?;
+        let mut values = vec![];
+
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Value::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Value::Array(values));
+                }
+                _ => return self.error("expected `,` or `]`"),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        self.expect("\"")?;
+        let mut bytes = vec![];
+
+        loop {
+            match self.peek() {
+                None => return self.error("unterminated string"),
+                Some(b'"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let escaped = match self.peek() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => self.unicode_escape()?,
+                        _ => return self.error("invalid escape sequence"),
+                    };
+                    self.pos += 1;
+                    let mut buf = [0; 4];
+                    bytes.extend_from_slice(escaped.encode_utf8(&mut buf).as_bytes());
+                }
+                Some(b) => {
+                    bytes.push(b);
+                    self.pos += 1;
+                }
+            }
+        }
+
+        String::from_utf8(bytes).or_else(|_| self.error("invalid utf-8 in string"))
+    }
+
+    /// Parses the hex digits of a `\u` escape. Leaves the position on the last digit.
+    fn unicode_escape(&mut self) -> Result<char, Error> {
+        let read_hex = |p: &mut Self| -> Result<u32, Error> {
+            let digits = p
+                .bytes
+                .get(p.pos + 1..p.pos + 5)
+                .and_then(|d| std::str::from_utf8(d).ok())
+                .and_then(|d| u32::from_str_radix(d, 16).ok());
+            match digits {
+                Some(d) => {
+                    p.pos += 4;
+                    Ok(d)
+                }
+                None => p.error("invalid unicode escape"),
+            }
+        };
+
+        let high = read_hex(self)?;
+        let code = if (0xd800..0xdc00).contains(&high) {
+            // surrogate pair, e.g. for emoji in names.
+            self.pos += 1;
+            self.expect("\\u")?;
+            self.pos -= 1;
+            let low = read_hex(self)?;
+            0x10000 + ((high - 0xd800) << 10) + (low.wrapping_sub(0xdc00) & 0x3ff)
+        } else {
+            high
+        };
+
+        char::from_u32(code).map_or_else(|| self.error("invalid unicode escape"), Ok)
+    }
+
+    fn number(&mut self) -> Result<Value, Error> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|b| b.is_ascii_digit() || matches!(b, b'-' | b'+' | b'.' | b'e' | b'E'))
+        {
+            self.pos += 1;
+        }
+
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .map_or_else(|| self.error("invalid number"), |n| Ok(Value::Number(n)))
+    }
+}
+
+/// Parses a JSON document.
+pub fn parse(s: &str) -> Result<Value, Error> {
+    let mut parser = Parser { bytes: s.as_bytes(), pos: 0 };
+
+    let value = parser.value()?;
+    parser.skip_whitespace();
+
+    if parser.pos < parser.bytes.len() {
+        return parser.error("unexpected trailing characters");
+    }
+
+    Ok(value)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, Value};
+
+    #[test]
+    fn parses_scalars() {
+        assert_eq!(parse("null"), Ok(Value::Null));
+        assert_eq!(parse(" true "), Ok(Value::Bool(true)));
+        assert_eq!(parse("-12.5e1"), Ok(Value::Number(-125.0)));
+        assert_eq!(parse("\"a\\n\\u00e9\""), Ok(Value::String("a\né".into())));
+        assert_eq!(parse("\"\\ud83c\\udf84\""), Ok(Value::String("🎄".into())));
+    }
+
+    #[test]
+    fn parses_nested_documents() {
+        let value = parse(r#"{"a": [1, {"b": null}], "c": "d", "e": {}}"#).unwrap();
+        assert_eq!(value.get("c").and_then(Value::as_str), Some("d"));
+        assert_eq!(
+            value.get("a").and_then(Value::as_array).map(<[_]>::len),
+            Some(2)
+        );
+        assert_eq!(
+            value.get("e").and_then(Value::as_object).map(<[_]>::len),
+            Some(0)
+        );
+        assert_eq!(value.get("missing"), None);
+    }
+
+    #[test]
+    fn reports_error_positions() {
+        let err = parse(r#"{"a": tru}"#).unwrap_err();
+        assert_eq!(err.position, 6);
+        assert!(parse("[1, 2").is_err());
+        assert!(parse("{} x").is_err());
+    }
+}
@@ -0,0 +1,216 @@
+/// Module that persists the results of the last `cargo all` run.
+/// Answers are stored for every run, benchmark times only for runs with `--time`. `cargo status`
+/// reads this file, so that it does not need to run every solution again.
+use std::{collections::BTreeMap, fmt::Display, fs, io, time::SystemTime};
+
+use crate::template::datetime;
+use crate::template::readme_benchmarks::Timings;
+use crate::Day;
+
+static LAST_RUN_PATH: &str = "data/.timings";
+static HEADER: &str = "# day\trecorded_at\ttotal_nanos\tpart_1\tpart_2";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(s) => write!(f, "{s}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// Seconds since the unix epoch.
+    pub recorded_at: i64,
+    /// Total runtime of the last benchmarked run.
+    pub total_nanos: Option<f64>,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct LastRun {
+    entries: BTreeMap<Day, Entry>,
+}
+
+/// Escapes tabs and newlines, so that multi-line answers fit on one line.
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some(c) => out.push(c),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+fn parse_answer(s: &str) -> Option<String> {
+    (s != "-").then(|| unescape(s))
+}
+
+fn parse_entry(line: &str) -> Result<(Day, Entry), Error> {
+    let err = || Error::Parser(format!("Malformed timings entry: {line}"));
+
+    let fields: Vec<&str> = line.split('\t').collect();
+    let [day, recorded_at, total_nanos, part_1, part_2] = fields[..] else {
+        return Err(err());
+    };
+
+    let total_nanos = match total_nanos {
+        "-" => None,
+        s => Some(s.parse().map_err(|_| err())?),
+    };
+
+    Ok((
+        day.parse().map_err(|_| err())?,
+        Entry {
+            recorded_at: recorded_at.parse().map_err(|_| err())?,
+            total_nanos,
+            part_1: parse_answer(part_1),
+            part_2: parse_answer(part_2),
+        },
+    ))
+}
+
+impl LastRun {
+    /// Loads the last run. A missing file means that `cargo all` has not been run yet.
+    pub fn load() -> Result<Self, Error> {
+        match fs::read_to_string(LAST_RUN_PATH) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let entries = s
+            .lines()
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .map(parse_entry)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { entries })
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        fs::write(LAST_RUN_PATH, self.to_string())?;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn get(&self, day: Day) -> Option<&Entry> {
+        self.entries.get(&day)
+    }
+
+    /// Records the results of a day. Untimed runs keep the benchmark time of the previous run.
+    pub fn record(&mut self, timings: &Timings, is_timed: bool) {
+        let previous = self.entries.get(&timings.day).and_then(|e| e.total_nanos);
+
+        self.entries.insert(
+            timings.day,
+            Entry {
+                recorded_at: datetime::unix_seconds(SystemTime::now()),
+                total_nanos: if is_timed {
+                    Some(timings.total_nanos)
+                } else {
+                    previous
+                },
+                part_1: timings.part_1_answer.clone(),
+                part_2: timings.part_2_answer.clone(),
+            },
+        );
+    }
+}
+
+impl Display for LastRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let answer = |a: &Option<String>| a.as_deref().map_or_else(|| "-".into(), escape);
+
+        writeln!(f, "{HEADER}")?;
+        for (day, e) in &self.entries {
+            writeln!(
+                f,
+                "{day}\t{}\t{}\t{}\t{}",
+                e.recorded_at,
+                e.total_nanos.map_or_else(|| "-".into(), |n| n.to_string()),
+                answer(&e.part_1),
+                answer(&e.part_2)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::LastRun;
+    use crate::day;
+    use crate::template::readme_benchmarks::Timings;
+
+    #[test]
+    fn roundtrips_entries() {
+        let mut last_run = LastRun::default();
+        last_run.record(
+            &Timings {
+                part_1_answer: Some("#.\t\\\n.#".into()),
+                total_nanos: 1500_f64,
+                ..Timings::new(day!(3))
+            },
+            true,
+        );
+        last_run.record(&Timings::new(day!(1)), false);
+
+        let parsed = LastRun::parse(&last_run.to_string()).unwrap();
+        assert_eq!(parsed, last_run);
+        assert_eq!(
+            parsed.get(day!(3)).unwrap().part_1.as_deref(),
+            Some("#.\t\\\n.#")
+        );
+        assert_eq!(parsed.get(day!(1)).unwrap().total_nanos, None);
+    }
+
+    #[test]
+    fn keeps_benchmark_of_untimed_runs() {
+        let mut last_run = LastRun::default();
+        let timings = Timings {
+            total_nanos: 1500_f64,
+            ..Timings::new(day!(1))
+        };
+        last_run.record(&timings, true);
+        last_run.record(&Timings::new(day!(1)), false);
+        assert_eq!(last_run.get(day!(1)).unwrap().total_nanos, Some(1500_f64));
+    }
+
+    #[test]
+    fn rejects_malformed_entries() {
+        assert!(LastRun::parse("01\tyesterday\t-\t-\t-").is_err());
+        assert!(LastRun::parse("01\t0\t-\t-").is_err());
+    }
+}
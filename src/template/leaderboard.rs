@@ -0,0 +1,330 @@
+/// Module that reads and renders private leaderboards.
+/// See the JSON API section of a private leaderboard page for the format of the data.
+use std::{collections::BTreeMap, fmt::Display, time::Duration};
+
+use crate::template::{datetime, json, ANSI_BOLD, ANSI_RESET};
+use crate::{all_days, Day};
+
+#[derive(Debug)]
+pub enum Error {
+    Json(json::Error),
+    Format(String),
+}
+
+impl From<json::Error> for Error {
+    fn from(e: json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Json(e) => write!(f, "{e}"),
+            Error::Format(s) => write!(f, "unexpected leaderboard format: {s}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Member {
+    pub id: i64,
+    pub name: Option<String>,
+    pub local_score: i64,
+    pub stars: i64,
+    /// Timestamps (seconds since the unix epoch) of both stars for every day with at least one star.
+    pub completions: BTreeMap<Day, [Option<i64>; 2]>,
+}
+
+impl Member {
+    /// Anonymous users are shown with their id, same as on the site.
+    #[must_use]
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+}
+
+#[derive(Debug)]
+pub struct Leaderboard {
+    pub event: i64,
+    pub members: Vec<Member>,
+}
+
+fn parse_member(value: &json::Value) -> Result<Member, Error> {
+    let int = |key: &str| {
+        value
+            .get(key)
+            .and_then(json::Value::as_i64)
+            .ok_or_else(|| Error::Format(format!("member is missing `{key}`")))
+    };
+
+    let mut completions = BTreeMap::new();
+    let days = value
+        .get("completion_day_level")
+        .and_then(json::Value::as_object)
+        .unwrap_or_default();
+
+    for (day, parts) in days {
+        let day: Day = day
+            .parse()
+            .map_err(|_| Error::Format(format!("invalid day `{day}`")))?;
+        let star = |part: &str| {
+            parts
+                .get(part)
+                .and_then(|p| p.get("get_star_ts"))
+                .and_then(json::Value::as_i64)
+        };
+        completions.insert(day, [star("1"), star("2")]);
+    }
+
+    Ok(Member {
+        id: int("id")?,
+        name: value
+            .get("name")
+            .and_then(json::Value::as_str)
+            .map(String::from),
+        local_score: int("local_score")?,
+        stars: int("stars")?,
+        completions,
+    })
+}
+
+/// Parses the leaderboard JSON. Members are sorted by local score, then by id.
+pub fn parse(s: &str) -> Result<Leaderboard, Error> {
+    let value = json::parse(s)?;
+
+    let event = value
+        .get("event")
+        .and_then(|e| {
+            e.as_str()
+                .and_then(|e| e.parse().ok())
+                .or_else(|| e.as_i64())
+        })
+        .ok_or_else(|| Error::Format("missing `event`".into()))?;
+
+    let mut members = value
+        .get("members")
+        .and_then(json::Value::as_object)
+        .ok_or_else(|| Error::Format("missing `members`".into()))?
+        .iter()
+        .map(|(_, member)| parse_member(member))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    members.sort_by_key(|m| (-m.local_score, m.id));
+
+    Ok(Leaderboard { event, members })
+}
+
+fn star_glyph(stars: Option<&[Option<i64>; 2]>) -> &'static str {
+    match stars {
+        Some([Some(_), Some(_)]) => "★",
+        Some([Some(_), None]) => "☆",
+        _ => "·",
+    }
+}
+
+/// Formats the time between a puzzle unlocking and a star, e.g. `00:12:34`.
+fn format_since(from: i64, to: Option<i64>) -> String {
+    match to {
+        Some(to) if to >= from => {
+            datetime::format_countdown(Duration::from_secs(to.abs_diff(from)))
+        }
+        _ => "-".into(),
+    }
+}
+
+/// Renders ranks, local scores and a star per day for every member.
+/// Members with the same score share a rank.
+pub fn render_overview(leaderboard: &Leaderboard) -> String {
+    let days: Vec<Day> = all_days().collect();
+    let mut lines = vec![];
+
+    let day_digits = |digit: fn(u8) -> char| {
+        days.iter()
+            .map(|d| digit(d.into_inner()))
+            .collect::<String>()
+    };
+    let tens = day_digits(|d| {
+        if d < 10 {
+            ' '
+        } else {
+            char::from(b'0' + d / 10)
+        }
+    });
+    let units = day_digits(|d| char::from(b'0' + d % 10));
+
+    lines.push(format!(
+        "{ANSI_BOLD}Leaderboard {}{ANSI_RESET}",
+        leaderboard.event
+    ));
+    lines.push(String::new());
+    lines.push(format!("{:17}{tens}", ""));
+    lines.push(format!("{:>4}  {:>5} {:>3}  {units}", "", "Score", "★"));
+
+    let mut rank = 0;
+    let mut previous_score = None;
+
+    for (i, member) in leaderboard.members.iter().enumerate() {
+        if previous_score != Some(member.local_score) {
+            rank = i + 1;
+            previous_score = Some(member.local_score);
+        }
+
+        let grid: String = days
+            .iter()
+            .map(|day| star_glyph(member.completions.get(day)))
+            .collect();
+
+        lines.push(format!(
+            "{:>4}) {:>5} {:>3}  {grid}  {}",
+            rank,
+            member.local_score,
+            member.stars,
+            member.display_name()
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Renders the star timestamps of a single day, relative to when the puzzle unlocked.
+/// The delta shows how long it took to get from the first to the second star.
+pub fn render_day(leaderboard: &Leaderboard, day: Day, unlocked_at: i64) -> String {
+    let mut members: Vec<(&Member, [Option<i64>; 2])> = leaderboard
+        .members
+        .iter()
+        .filter_map(|m| m.completions.get(&day).map(|stars| (m, *stars)))
+        .collect();
+
+    // order like the site: by completion time of the second star, then of the first.
+    members.sort_by_key(|(_, [one, two])| (two.unwrap_or(i64::MAX), one.unwrap_or(i64::MAX)));
+
+    let mut lines = vec![
+        format!("{ANSI_BOLD}Day {day}{ANSI_RESET}"),
+        String::new(),
+        format!(
+            "{:>4}  {:>12}  {:>12}  {:>12}  Name",
+            "", "Part 1", "Part 2", "Delta"
+        ),
+    ];
+
+    if members.is_empty() {
+        lines.push("Nobody has a star for this day yet.".into());
+    }
+
+    for (i, (member, [one, two])) in members.iter().enumerate() {
+        let delta = match (one, two) {
+            (Some(one), Some(_)) => format_since(*one, *two),
+            _ => "-".into(),
+        };
+
+        lines.push(format!(
+            "{:>4}) {:>12}  {:>12}  {:>12}  {}",
+            i + 1,
+            format_since(unlocked_at, *one),
+            format_since(unlocked_at, *two),
+            delta,
+            member.display_name()
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// The latest day that any member has a star for.
+#[must_use]
+pub fn latest_day(leaderboard: &Leaderboard) -> Option<Day> {
+    leaderboard
+        .members
+        .iter()
+        .filter_map(|m| m.completions.keys().max())
+        .max()
+        .copied()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{latest_day, parse, render_day, render_overview};
+    use crate::day;
+
+    const FIXTURE: &str = r#"{
+        "owner_id": 1,
+        "event": "2023",
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "stars": 3, "local_score": 10, "global_score": 0,
+                "last_star_ts": 1701410000,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1701407000, "star_index": 1}, "2": {"get_star_ts": 1701407600, "star_index": 2}},
+                    "2": {"1": {"get_star_ts": 1701494000, "star_index": 3}}
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 2, "local_score": 6, "global_score": 0,
+                "last_star_ts": 1701408000,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1701407300, "star_index": 1}, "2": {"get_star_ts": 1701408000, "star_index": 2}}
+                }
+            },
+            "3": {
+                "id": 3, "name": "Carol", "stars": 0, "local_score": 6, "global_score": 0,
+                "last_star_ts": 0, "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn parses_members_by_score() {
+        let leaderboard = parse(FIXTURE).unwrap();
+        assert_eq!(leaderboard.event, 2023);
+        let ids: Vec<i64> = leaderboard.members.iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(
+            leaderboard.members[0].completions[&day!(2)],
+            [Some(1701494000), None]
+        );
+        assert_eq!(latest_day(&leaderboard), Some(day!(2)));
+    }
+
+    #[test]
+    fn renders_overview() {
+        let out = render_overview(&parse(FIXTURE).unwrap());
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[2], "                          1111111111222222");
+        assert_eq!(lines[3], "      Score   ★  1234567890123456789012345");
+        assert_eq!(
+            lines[4],
+            "   1)    10   3  ★☆·······················  Alice"
+        );
+        assert_eq!(
+            lines[5],
+            "   2)     6   2  ★························  (anonymous user #2)"
+        );
+        assert_eq!(
+            lines[6],
+            "   2)     6   0  ·························  Carol"
+        );
+    }
+
+    #[test]
+    fn renders_day_with_deltas() {
+        let out = render_day(&parse(FIXTURE).unwrap(), day!(1), 1701406800);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines[3],
+            "   1)     00:03:20      00:13:20      00:10:00  Alice"
+        );
+        assert_eq!(
+            lines[4],
+            "   2)     00:08:20      00:20:00      00:11:40  (anonymous user #2)"
+        );
+    }
+
+    #[test]
+    fn rejects_unexpected_documents() {
+        assert!(parse("[]").is_err());
+        assert!(parse(r#"{"event": "2023", "members": {"1": {"id": 1}}}"#).is_err());
+    }
+}
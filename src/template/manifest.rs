@@ -0,0 +1,185 @@
+/// Module that reads example manifests, e.g. `data/examples/08.toml`.
+/// A manifest lists example files and their expected answers for each part:
+///
+/// ```toml
+/// [[part_one]]
+/// file = "08-a.txt"
+/// expected = 2
+///
+/// [[part_two]]
+/// file = "08-c.txt"
+/// expected = "LR"
+/// ```
+///
+/// Only this subset of TOML is supported. The module is also compiled into the build script,
+/// which generates a test for every entry, so it must not depend on anything but `std`.
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub part: u8,
+    /// File name relative to `data/examples`.
+    pub file: String,
+    /// The expected answer, formatted as the solution would display it.
+    pub expected: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Error {
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+fn parse_value(s: &str) -> Option<String> {
+    if let Some(s) = s.strip_prefix('"') {
+        return s.strip_suffix('"').map(str::to_string);
+    }
+
+    let is_integer = s
+        .strip_prefix('-')
+        .unwrap_or(s)
+        .chars()
+        .all(|c| c.is_ascii_digit());
+    (!s.is_empty() && is_integer).then(|| s.to_string())
+}
+
+/// Removes a trailing comment, unless the `#` is part of a string.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+pub fn parse(s: &str) -> Result<Vec<Example>, Error> {
+    // (part, file, expected, line of the table header)
+    let mut tables: Vec<(u8, Option<String>, Option<String>, usize)> = vec![];
+
+    for (i, line) in s.lines().enumerate() {
+        let line_number = i + 1;
+        let err = |message: &str| Error {
+            line: line_number,
+            message: message.into(),
+        };
+
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        match line {
+            "[[part_one]]" => tables.push((1, None, None, line_number)),
+            "[[part_two]]" => tables.push((2, None, None, line_number)),
+            _ => {
+                let (key, value) = line
+                    .split_once('=')
+                    .ok_or_else(|| err("expected `key = value` or a table header"))?;
+
+                let table = tables
+                    .last_mut()
+                    .ok_or_else(|| err("expected `[[part_one]]` or `[[part_two]]` first"))?;
+
+                let value = parse_value(value.trim())
+                    .ok_or_else(|| err("expected a quoted string or an integer"))?;
+
+                match key.trim() {
+                    "file" => table.1 = Some(value),
+                    "expected" => table.2 = Some(value),
+                    key => return Err(err(&format!("unknown key `{key}`"))),
+                }
+            }
+        }
+    }
+
+    tables
+        .into_iter()
+        .map(|(part, file, expected, line)| match (file, expected) {
+            (Some(file), Some(expected)) => Ok(Example { part, file, expected }),
+            (None, _) => Err(Error {
+                line,
+                message: "missing `file`".into(),
+            }),
+            (_, None) => Err(Error {
+                line,
+                message: "missing `expected`".into(),
+            }),
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, Error, Example};
+
+    #[test]
+    fn parses_examples() {
+        let manifest = r#"
+# examples of day 8
+[[part_one]]
+file = "08-a.txt"
+expected = 2
+
+[[part_one]]
+file = "08-b.txt" # the longer one
+expected = -6
+
+[[part_two]]
+expected = "L#R"
+file = "08-c.txt"
+"#;
+
+        assert_eq!(
+            parse(manifest).unwrap(),
+            vec![
+                Example {
+                    part: 1,
+                    file: "08-a.txt".into(),
+                    expected: "2".into()
+                },
+                Example {
+                    part: 1,
+                    file: "08-b.txt".into(),
+                    expected: "-6".into()
+                },
+                Example {
+                    part: 2,
+                    file: "08-c.txt".into(),
+                    expected: "L#R".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_lines_of_errors() {
+        let err = |line, message: &str| Err(Error { line, message: message.into() });
+
+        assert_eq!(
+            parse("file = \"a.txt\""),
+            err(1, "expected `[[part_one]]` or `[[part_two]]` first")
+        );
+        assert_eq!(
+            parse("[[part_one]]\nexpected = 12a"),
+            err(2, "expected a quoted string or an integer")
+        );
+        assert_eq!(
+            parse("\n[[part_two]]\nfile = \"a.txt\""),
+            err(2, "missing `expected`")
+        );
+        assert_eq!(
+            parse("[[part_three]]"),
+            err(1, "expected `key = value` or a table header")
+        );
+    }
+}
@@ -0,0 +1,325 @@
+/// Renders puzzle descriptions to the terminal.
+/// Only the subset of markdown that aoc-cli produces for puzzle descriptions is supported.
+use crate::template::puzzle::Block;
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+pub const DEFAULT_WIDTH: usize = 80;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Style {
+    bold: bool,
+    italic: bool,
+}
+
+impl Style {
+    fn ansi(self) -> String {
+        let mut s = String::new();
+        if self.bold {
+            s.push_str(ANSI_BOLD);
+        }
+        if self.italic {
+            s.push_str(ANSI_ITALIC);
+        }
+        s
+    }
+}
+
+type Word = Vec<(char, Style)>;
+
+#[derive(Default)]
+struct InlineState {
+    strong: bool,
+    em: bool,
+    underscore: bool,
+    code: bool,
+}
+
+impl InlineState {
+    fn style(&self) -> Style {
+        Style {
+            // AoC highlights `<em>` text, which reads best as bold in a terminal.
+            bold: self.strong || self.em,
+            italic: self.underscore || self.code,
+        }
+    }
+}
+
+/// Byte offsets of the closing `]` and the end of the target if `text` starts with a link like `[text](target)`.
+fn link_at(text: &str) -> Option<(usize, usize)> {
+    let mut depth = 0;
+    let close = text.char_indices().skip(1).find_map(|(i, c)| match c {
+        '[' => {
+            depth += 1;
+            None
+        }
+        ']' if depth == 0 => Some(i),
+        ']' => {
+            depth -= 1;
+            None
+        }
+        _ => None,
+    })?;
+
+    let target = text[close + 1..].strip_prefix('(')?;
+    let end = target.find(')')?;
+    Some((close, close + 2 + end + 1))
+}
+
+/// Parses inline markup into styled characters.
+fn parse_inline(text: &str) -> Vec<(char, Style)> {
+    let mut state = InlineState::default();
+    let mut out = vec![];
+    let mut prev = None;
+    // byte offsets of the closing `]` of the current link and the end of its target.
+    let mut link = None;
+    let mut i = 0;
+
+    while let Some(c) = text[i..].chars().next() {
+        let rest = &text[i..];
+        let next = rest[c.len_utf8()..].chars().next();
+        let mut len = c.len_utf8();
+
+        match c {
+            ']' if link.is_some_and(|(close, _)| close == i) => {
+                // skip the link target, only its text is displayed.
+                let (_, end) = link.take().unwrap_or_default();
+                len = end - i;
+            }
+            '\\' if next.is_some_and(|n| n.is_ascii_punctuation()) => {
+                out.push((rest.as_bytes()[1] as char, state.style()));
+                len = 2;
+            }
+            '*' if next == Some('*') => {
+                state.strong = !state.strong;
+                len = 2;
+            }
+            '*' => state.em = !state.em,
+            '`' => state.code = !state.code,
+            '_' if !state.underscore
+                && !prev.is_some_and(char::is_alphanumeric)
+                && next.is_some_and(|n| !n.is_whitespace()) =>
+            {
+                state.underscore = true;
+            }
+            '_' if state.underscore && !next.is_some_and(char::is_alphanumeric) => {
+                state.underscore = false;
+            }
+            '<' => {
+                let tag = ["<em>", "</em>", "<code>", "</code>"]
+                    .into_iter()
+                    .find(|tag| rest.starts_with(tag));
+                match tag {
+                    Some(tag) => {
+                        if tag.contains("em") {
+                            state.em = !tag.starts_with("</");
+                        } else {
+                            state.code = !tag.starts_with("</");
+                        }
+                        len = tag.len();
+                    }
+                    None => out.push((c, state.style())),
+                }
+            }
+            '[' if link.is_none() => match link_at(rest) {
+                Some((close, end)) => link = Some((i + close, i + end)),
+                None => out.push((c, state.style())),
+            },
+            c => out.push((c, state.style())),
+        }
+
+        prev = rest[..len].chars().next_back();
+        i += len;
+    }
+
+    out
+}
+
+/// Styles a line of a code block. Code is printed as-is, except for the `<em>` highlights of AoC.
+fn parse_code(line: &str) -> Word {
+    let mut out = vec![];
+    let mut bold = false;
+    let mut rest = line;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(tail) = rest.strip_prefix("<em>") {
+            bold = true;
+            rest = tail;
+        } else if let Some(tail) = rest.strip_prefix("</em>") {
+            bold = false;
+            rest = tail;
+        } else {
+            out.push((c, Style { bold, italic: false }));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    out
+}
+
+fn split_words(chars: Vec<(char, Style)>) -> Vec<Word> {
+    chars
+        .split(|(c, _)| c.is_whitespace())
+        .filter(|word| !word.is_empty())
+        .map(<[(char, Style)]>::to_vec)
+        .collect()
+}
+
+fn render_word(word: &Word) -> String {
+    let mut s = String::new();
+    let mut current = Style::default();
+
+    for &(c, style) in word {
+        if style != current {
+            s.push_str(ANSI_RESET);
+            s.push_str(&style.ansi());
+            current = style;
+        }
+        s.push(c);
+    }
+
+    if current != Style::default() {
+        s.push_str(ANSI_RESET);
+    }
+
+    s
+}
+
+/// Wraps styled text to `width` visible characters. Every line after the first is prefixed with `indent`.
+fn wrap(text: &str, width: usize, first_prefix: &str, indent: &str) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = first_prefix.to_string();
+    let mut line_width = first_prefix.chars().count();
+    let mut is_empty = true;
+
+    for word in split_words(parse_inline(text)) {
+        if !is_empty && line_width + 1 + word.len() > width {
+            lines.push(line);
+            line = indent.to_string();
+            line_width = indent.chars().count();
+            is_empty = true;
+        }
+
+        if !is_empty {
+            line.push(' ');
+            line_width += 1;
+        }
+
+        line.push_str(&render_word(&word));
+        line_width += word.len();
+        is_empty = false;
+    }
+
+    lines.push(line);
+    lines
+}
+
+/// Renders blocks of a puzzle description with ANSI styles, wrapping text to `width` characters.
+pub fn render(blocks: &[Block], width: usize) -> String {
+    let mut out: Vec<String> = vec![];
+    let mut previous_is_list_item = false;
+
+    for block in blocks {
+        let is_list_item = matches!(block, Block::ListItem(_));
+
+        // consecutive list items are rendered without a blank line in between.
+        let continues_list = is_list_item && previous_is_list_item;
+        if out.last().is_some() && !continues_list {
+            out.push(String::new());
+        }
+        previous_is_list_item = is_list_item;
+
+        match block {
+            Block::Heading(text) => {
+                out.push(format!("{ANSI_BOLD}--- {text} ---{ANSI_RESET}"));
+            }
+            Block::Paragraph(text) => out.extend(wrap(text, width, "", "")),
+            Block::ListItem(text) => out.extend(wrap(text, width, "  • ", "    ")),
+            Block::Code(code) => {
+                out.extend(
+                    code.lines()
+                        .map(|line| format!("    {}", render_word(&parse_code(line)))),
+                );
+            }
+        }
+    }
+
+    out.join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{render, wrap};
+    use crate::template::puzzle::blocks;
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    #[test]
+    fn wraps_paragraphs() {
+        let lines = wrap("one two three four five", 10, "", "");
+        assert_eq!(lines, vec!["one two", "three four", "five"]);
+    }
+
+    #[test]
+    fn wraps_by_visible_width() {
+        let lines = wrap("*one* *two* three", 9, "", "");
+        assert_eq!(
+            lines,
+            vec![
+                format!(
+                    "{ANSI_RESET}{ANSI_BOLD}one{ANSI_RESET} {ANSI_RESET}{ANSI_BOLD}two{ANSI_RESET}"
+                ),
+                "three".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_emphasis() {
+        let out = render(&blocks("a *b* _c_ `d` **e**"), 80);
+        assert_eq!(
+            out,
+            format!(
+                "a {r}{b}b{r} {r}{i}c{r} {r}{i}d{r} {r}{b}e{r}",
+                r = ANSI_RESET,
+                b = ANSI_BOLD,
+                i = ANSI_ITALIC
+            )
+        );
+    }
+
+    #[test]
+    fn renders_links_and_escapes() {
+        let out = render(
+            &blocks("see [the site](https://adventofcode.com) \\*now\\*"),
+            80,
+        );
+        assert_eq!(out, "see the site *now*");
+    }
+
+    #[test]
+    fn keeps_brackets_outside_links() {
+        let out = render(&blocks("a[0] and [x](y), [z] (w) and [[1]](v)"), 80);
+        assert_eq!(out, "a[0] and x, [z] (w) and [1]");
+    }
+
+    #[test]
+    fn renders_headings_lists_and_code() {
+        let md = "\\--- Day 1: Test ---\n----------\n\n* one\n* two\n\n```\n1abc2\n```\n\nsnake_case stays";
+        let out = render(&blocks(md), 80);
+        assert_eq!(
+            out,
+            format!("{ANSI_BOLD}--- Day 1: Test ---{ANSI_RESET}\n\n  • one\n  • two\n\n    1abc2\n\nsnake_case stays")
+        );
+    }
+
+    #[test]
+    fn renders_code_verbatim() {
+        let md = "```\n467..114..\n...*......\n#_[a](b)\\`x`\n<em>4</em>67\n```";
+        let out = render(&blocks(md), 80);
+        assert_eq!(
+            out,
+            format!(
+                "    467..114..\n    ...*......\n    #_[a](b)\\`x`\n    {ANSI_RESET}{ANSI_BOLD}4{ANSI_RESET}67"
+            )
+        );
+    }
+}
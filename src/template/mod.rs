@@ -2,13 +2,31 @@ use crate::Day;
 use std::{env, fs};
 
 pub mod aoc_cli;
+pub mod cache;
+pub mod chart;
 pub mod commands;
+pub mod csv;
+pub mod datetime;
+pub mod http;
+pub mod json;
+pub mod last_run;
+pub mod leaderboard;
+pub mod manifest;
+pub mod markdown;
+pub mod puzzle;
 pub mod readme_benchmarks;
 pub mod runner;
+pub mod session;
+pub mod stars;
+pub mod unlock;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_DIM: &str = "\x1b[2m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
+pub const ANSI_YELLOW: &str = "\x1b[33m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
@@ -32,17 +50,24 @@ pub fn read_file_part(folder: &str, day: Day, part: &str) -> String {
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Optionally takes the parser of the solution as a second argument, so parsing is timed separately.
+/// Also includes the tests that the build script generates from `data/examples/<day>.toml`.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    ($day:expr $(, $parse:expr)?) => {
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
 
         fn main() {
             use advent_of_code::template::runner::*;
             let input = advent_of_code::template::read_file("inputs", DAY);
+            $(run_parse($parse, &input);)?
             run_part(part_one, &input, DAY, 1);
             run_part(part_two, &input, DAY, 2);
+            print_peak_memory();
         }
+
+        // tests generated from `data/examples/<day>.toml` by the build script.
+        include!(concat!(env!("OUT_DIR"), "/example_tests_", stringify!($day), ".rs"));
     };
 }
@@ -0,0 +1,232 @@
+/// Helpers that extract information from puzzle descriptions saved by `cargo download`.
+/// aoc-cli writes descriptions as markdown, with the day title rendered as `\--- Day 1: Title ---`.
+use std::{env, fs, io};
+
+use crate::Day;
+
+/// A top-level block of a puzzle description.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Block {
+    Heading(String),
+    Paragraph(String),
+    ListItem(String),
+    Code(String),
+}
+
+#[must_use]
+pub fn get_puzzle_path(day: Day) -> String {
+    format!("data/puzzles/{day}.md")
+}
+
+/// Reads the cached puzzle description for a day.
+pub fn read(day: Day) -> Result<String, io::Error> {
+    let cwd = env::current_dir()?;
+    fs::read_to_string(cwd.join(get_puzzle_path(day)))
+}
+
+fn is_heading(line: &str) -> bool {
+    let line = line.trim_start_matches('\\');
+    line.starts_with('#') || (line.starts_with("--- ") && line.ends_with(" ---"))
+}
+
+fn is_underline(line: &str) -> bool {
+    !line.is_empty() && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '='))
+}
+
+fn heading_text(line: &str) -> String {
+    line.trim_start_matches(['\\', '#'])
+        .trim_matches(|c: char| c == '-' || c.is_whitespace())
+        .to_string()
+}
+
+fn list_item_text(line: &str) -> Option<&str> {
+    ["* ", "- ", "+ "]
+        .iter()
+        .find_map(|marker| line.trim_start().strip_prefix(marker))
+}
+
+/// Splits a puzzle description into headings, paragraphs, list items and code blocks.
+pub fn blocks(md: &str) -> Vec<Block> {
+    let mut blocks = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    let mut list_item: Option<Vec<&str>> = None;
+    let mut lines = md.lines();
+
+    let flush =
+        |paragraph: &mut Vec<&str>, list_item: &mut Option<Vec<&str>>, blocks: &mut Vec<Block>| {
+            if !paragraph.is_empty() {
+                blocks.push(Block::Paragraph(paragraph.join("\n")));
+                paragraph.clear();
+            }
+            if let Some(item) = list_item.take() {
+                blocks.push(Block::ListItem(item.join("\n")));
+            }
+        };
+
+    while let Some(line) = lines.next() {
+        if line.trim_start().starts_with("```") {
+            flush(&mut paragraph, &mut list_item, &mut blocks);
+            let code: Vec<&str> = lines
+                .by_ref()
+                .take_while(|l| !l.trim_start().starts_with("```"))
+                .collect();
+            blocks.push(Block::Code(code.join("\n")));
+        } else if line.trim().is_empty() {
+            flush(&mut paragraph, &mut list_item, &mut blocks);
+        } else if is_heading(line) {
+            flush(&mut paragraph, &mut list_item, &mut blocks);
+            blocks.push(Block::Heading(heading_text(line)));
+        } else if is_underline(line.trim()) {
+            // setext-style underline of the preceding heading or paragraph line.
+            if let Some(last) = paragraph.pop() {
+                flush(&mut paragraph, &mut list_item, &mut blocks);
+                blocks.push(Block::Heading(heading_text(last)));
+            }
+        } else if let Some(text) = list_item_text(line) {
+            flush(&mut paragraph, &mut list_item, &mut blocks);
+            list_item = Some(vec![text]);
+        } else if let Some(item) = list_item.as_mut() {
+            item.push(line.trim());
+        } else {
+            paragraph.push(line);
+        }
+    }
+
+    flush(&mut paragraph, &mut list_item, &mut blocks);
+    blocks
+}
+
+/// Returns the blocks that belong to one part of the puzzle.
+/// Part two starts at the `Part Two` heading, which only exists once part one has been solved.
+pub fn part_blocks(blocks: Vec<Block>, part: u8) -> Option<Vec<Block>> {
+    let part_two_start = blocks
+        .iter()
+        .position(|block| matches!(block, Block::Heading(text) if text.starts_with("Part Two")));
+
+    match (part, part_two_start) {
+        (1, Some(start)) => Some(blocks.into_iter().take(start).collect()),
+        (1, None) => Some(blocks),
+        (2, Some(start)) => Some(blocks.into_iter().skip(start).collect()),
+        _ => None,
+    }
+}
+
+/// Returns the title of the puzzle, e.g. `Day 1: Trebuchet?!`.
+pub fn title(md: &str) -> Option<String> {
+    blocks(md).into_iter().find_map(|block| match block {
+        Block::Heading(text) if text.starts_with("Day ") => Some(text),
+        _ => None,
+    })
+}
+
+/// Returns the first paragraph of the puzzle, joined into a single line.
+pub fn first_paragraph(md: &str) -> Option<String> {
+    blocks(md).into_iter().find_map(|block| match block {
+        Block::Paragraph(text) => Some(text.lines().map(str::trim).collect::<Vec<_>>().join(" ")),
+        _ => None,
+    })
+}
+
+/// Returns the example input of the puzzle.
+/// This is the first code block that follows a paragraph mentioning an example, or the first code block if none does.
+pub fn example(md: &str) -> Option<String> {
+    let blocks = blocks(md);
+
+    let introduced = blocks.windows(2).find_map(|pair| match pair {
+        [Block::Paragraph(text), Block::Code(code)] if text.to_lowercase().contains("example") => {
+            Some(code.clone())
+        }
+        _ => None,
+    });
+
+    introduced.or_else(|| {
+        blocks.into_iter().find_map(|block| match block {
+            Block::Code(code) => Some(code),
+            _ => None,
+        })
+    })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{blocks, example, first_paragraph, part_blocks, title, Block};
+
+    const PUZZLE: &str = r"\--- Day 1: Trebuchet?! ---
+----------
+
+Something is wrong with global snow production,
+and you've been selected to take a look.
+
+You try to ask why they can't just use a weather machine:
+
+```
+not the example
+```
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+In this example, the calibration values are `12` and `38`.
+";
+
+    #[test]
+    fn splits_blocks() {
+        let blocks = blocks(PUZZLE);
+        assert_eq!(blocks[0], Block::Heading("Day 1: Trebuchet?!".into()));
+        assert_eq!(blocks.len(), 7);
+        assert_eq!(blocks[4], Block::Paragraph("For example:".into()));
+    }
+
+    #[test]
+    fn finds_title() {
+        assert_eq!(title(PUZZLE), Some("Day 1: Trebuchet?!".into()));
+        assert_eq!(
+            title("## --- Day 12: Hot Springs ---"),
+            Some("Day 12: Hot Springs".into())
+        );
+    }
+
+    #[test]
+    fn finds_first_paragraph() {
+        assert_eq!(
+            first_paragraph(PUZZLE),
+            Some("Something is wrong with global snow production, and you've been selected to take a look.".into())
+        );
+    }
+
+    #[test]
+    fn finds_example() {
+        assert_eq!(example(PUZZLE), Some("1abc2\npqr3stu8vwx".into()));
+        assert_eq!(example("text\n\n```\nfoo\n```"), Some("foo".into()));
+        assert_eq!(example("no code"), None);
+    }
+
+    #[test]
+    fn splits_list_items() {
+        let blocks = blocks("Some items:\n\n* one\n  continued\n* two\n\nAfter.");
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Paragraph("Some items:".into()),
+                Block::ListItem("one\ncontinued".into()),
+                Block::ListItem("two".into()),
+                Block::Paragraph("After.".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn splits_parts() {
+        let md = format!("{PUZZLE}\n\\--- Part Two ---\n----------\n\nMore text.");
+        let part_one = part_blocks(blocks(&md), 1).unwrap();
+        let part_two = part_blocks(blocks(&md), 2).unwrap();
+        assert_eq!(part_one.len(), 7);
+        assert_eq!(part_two[0], Block::Heading("Part Two".into()));
+        assert_eq!(part_two.len(), 2);
+        assert_eq!(part_blocks(blocks(PUZZLE), 2), None);
+    }
+}
@@ -1,6 +1,6 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fs, io, path::Path, str::FromStr, time::Duration};
 
 use crate::Day;
 
@@ -23,12 +23,144 @@ pub struct Timings {
     pub day: Day,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_nanos: Option<f64>,
+    pub part_2_nanos: Option<f64>,
+    pub part_1_samples: Option<u128>,
+    pub part_2_samples: Option<u128>,
+    pub part_1_median_nanos: Option<f64>,
+    pub part_2_median_nanos: Option<f64>,
+    pub part_1_answer: Option<String>,
+    pub part_2_answer: Option<String>,
+    pub parse: Option<String>,
+    pub memory: Option<String>,
     pub total_nanos: f64,
 }
 
+impl Timings {
+    /// Creates timings for a day that has not been benchmarked.
+    #[must_use]
+    pub fn new(day: Day) -> Self {
+        Self {
+            day,
+            part_1: None,
+            part_2: None,
+            part_1_nanos: None,
+            part_2_nanos: None,
+            part_1_samples: None,
+            part_2_samples: None,
+            part_1_median_nanos: None,
+            part_2_median_nanos: None,
+            part_1_answer: None,
+            part_2_answer: None,
+            parse: None,
+            memory: None,
+            total_nanos: 0_f64,
+        }
+    }
+}
+
+/// A column of the benchmarking table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Parse,
+    Part1,
+    Part2,
+    Total,
+    Memory,
+    Samples,
+    /// The share of a day in the total runtime.
+    Share,
+    /// An ASCII bar of the runtime of a day, relative to the slowest day.
+    Bar,
+}
+
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "parse" => Ok(Column::Parse),
+            "part1" => Ok(Column::Part1),
+            "part2" => Ok(Column::Part2),
+            "total" => Ok(Column::Total),
+            "memory" => Ok(Column::Memory),
+            "samples" => Ok(Column::Samples),
+            "share" => Ok(Column::Share),
+            "bar" => Ok(Column::Bar),
+            s => Err(format!(
+                "unknown column `{s}`, expecting one of parse, part1, part2, total, memory, samples, share, bar"
+            )),
+        }
+    }
+}
+
+impl Column {
+    fn title(self) -> &'static str {
+        match self {
+            Column::Parse => "Parse",
+            Column::Part1 => "Part 1",
+            Column::Part2 => "Part 2",
+            Column::Total => "Total",
+            Column::Memory => "Memory",
+            Column::Samples => "Samples",
+            Column::Share => "Share",
+            Column::Bar => "Runtime",
+        }
+    }
+}
+
+/// Parses a comma-separated list of columns, e.g. `part1,part2,share`.
+pub fn parse_columns(s: &str) -> Result<Vec<Column>, String> {
+    s.split(',').map(str::parse).collect()
+}
+
+/// Order of the rows of the benchmarking table. Timings sort the slowest day first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortBy {
+    Day,
+    Part1,
+    Part2,
+    Total,
+}
+
+impl FromStr for SortBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(SortBy::Day),
+            "part1" => Ok(SortBy::Part1),
+            "part2" => Ok(SortBy::Part2),
+            "total" => Ok(SortBy::Total),
+            s => Err(format!(
+                "unknown sort order `{s}`, expecting one of day, part1, part2, total"
+            )),
+        }
+    }
+}
+
+pub struct TableOptions {
+    pub columns: Vec<Column>,
+    pub sort: SortBy,
+    /// The file that the table is written to.
+    pub path: String,
+}
+
+impl Default for TableOptions {
+    fn default() -> Self {
+        Self {
+            columns: vec![Column::Part1, Column::Part2],
+            sort: SortBy::Day,
+            path: "README.md".into(),
+        }
+    }
+}
+
+const BAR_WIDTH: f64 = 20_f64;
+
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
 }
 
 #[must_use]
@@ -36,8 +168,9 @@ pub fn get_path_for_bin(day: Day) -> String {
     format!("./src/bin/{day}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Finds the range between the first and the last occurence of `marker`.
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -58,25 +191,99 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
+fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos as u64);
+    format!("{duration:.1?}")
+}
+
+fn format_samples(timing: &Timings) -> String {
+    match (timing.part_1_samples, timing.part_2_samples) {
+        (None, None) => "-".into(),
+        (a, b) => [a, b]
+            .map(|s| s.map_or_else(|| "-".into(), |s| s.to_string()))
+            .join(" / "),
+    }
+}
+
+fn format_bar(nanos: f64, max_nanos: f64) -> String {
+    let width = if max_nanos > 0_f64 {
+        (nanos / max_nanos * BAR_WIDTH).round()
+    } else {
+        0_f64
+    };
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let width = width as usize;
+    format!("`{:<2$}{}`", "#".repeat(width), "", BAR_WIDTH as usize)
+}
+
+fn format_cell(column: Column, timing: &Timings, total_nanos: f64, max_nanos: f64) -> String {
+    let code = |s: &Option<String>| format!("`{}`", s.as_deref().unwrap_or("-"));
+
+    match column {
+        Column::Parse => code(&timing.parse),
+        Column::Part1 => code(&timing.part_1),
+        Column::Part2 => code(&timing.part_2),
+        Column::Total => format!("`{}`", format_nanos(timing.total_nanos)),
+        Column::Memory => code(&timing.memory),
+        Column::Samples => format_samples(timing),
+        Column::Share if total_nanos > 0_f64 => {
+            format!("{:.1}%", timing.total_nanos / total_nanos * 100_f64)
+        }
+        Column::Share => "-".into(),
+        Column::Bar => format_bar(timing.total_nanos, max_nanos),
+    }
+}
+
+fn sort_timings(timings: &mut [Timings], sort: SortBy) {
+    let key = |t: &Timings| match sort {
+        SortBy::Day => 0_f64,
+        SortBy::Part1 => t.part_1_nanos.unwrap_or(0_f64),
+        SortBy::Part2 => t.part_2_nanos.unwrap_or(0_f64),
+        SortBy::Total => t.total_nanos,
+    };
+
+    // sorting is stable, days keep their order for equal timings.
+    timings.sort_by_key(|t| t.day);
+    timings.sort_by(|a, b| key(b).total_cmp(&key(a)));
+}
+
+fn construct_table(
+    prefix: &str,
+    mut timings: Vec<Timings>,
+    total_millis: f64,
+    options: &TableOptions,
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
+    let total_nanos = timings.iter().map(|t| t.total_nanos).sum::<f64>();
+    let max_nanos = timings.iter().map(|t| t.total_nanos).fold(0_f64, f64::max);
+    sort_timings(&mut timings, options.sort);
+
+    let titles: Vec<&str> = options.columns.iter().map(|c| c.title()).collect();
+    let alignment = vec![":---:"; options.columns.len() + 1];
+
     let mut lines: Vec<String> = vec![
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| Day | {} |", titles.join(" | ")),
+        format!("| {}  |", alignment.join(" | ")),
     ];
 
     for timing in timings {
         let path = get_path_for_bin(timing.day);
+        let cells: Vec<String> = options
+            .columns
+            .iter()
+            .map(|&column| format_cell(column, &timing, total_nanos, max_nanos))
+            .collect();
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | {} |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            cells.join(" | ")
         ));
     }
 
@@ -87,45 +294,71 @@ fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> St
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+fn update_content(
+    s: &mut String,
+    timings: Vec<Timings>,
+    total_millis: f64,
+    options: &TableOptions,
+) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table("##", timings, total_millis, options);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
+/// Writes the benchmarking table to the marker in the file at `options.path`.
+/// Files other than the readme are created with just the table if they do not exist yet.
+pub fn update(
+    timings: Vec<Timings>,
+    total_millis: f64,
+    options: &TableOptions,
+) -> Result<(), Error> {
+    let path = Path::new(&options.path);
+
+    let mut content = if path.exists() || options.path == TableOptions::default().path {
+        String::from_utf8_lossy(&fs::read(path)?).to_string()
+    } else {
+        MARKER.into()
+    };
+
+    update_content(&mut content, timings, total_millis, options)?;
+    fs::write(path, &content)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
+    use super::{parse_columns, update_content, Column, SortBy, TableOptions, Timings, MARKER};
     use crate::day;
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
-                day: day!(1),
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
+                part_1_nanos: Some(1e+10),
+                part_2_nanos: Some(2e+10),
+                part_1_samples: Some(100),
+                part_2_samples: Some(50),
                 total_nanos: 3e+10,
+                ..Timings::new(day!(1))
             },
             Timings {
-                day: day!(2),
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
+                part_1_nanos: Some(3e+10),
+                part_2_nanos: Some(4e+10),
                 total_nanos: 7e+10,
+                memory: Some("2.0 MiB".into()),
+                ..Timings::new(day!(2))
             },
             Timings {
-                day: day!(4),
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
+                part_1_nanos: Some(4e+10),
+                part_2_nanos: Some(5e+10),
                 total_nanos: 9e+10,
+                ..Timings::new(day!(4))
             },
         ]
     }
@@ -134,28 +367,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &TableOptions::default()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &TableOptions::default()).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &TableOptions::default()).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &TableOptions::default()).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &TableOptions::default()).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -163,7 +396,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, &TableOptions::default()).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -183,4 +416,45 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn parses_columns() {
+        assert_eq!(
+            parse_columns("part1,total,bar").unwrap(),
+            vec![Column::Part1, Column::Total, Column::Bar]
+        );
+        assert!(parse_columns("part1,foo").is_err());
+    }
+
+    #[test]
+    fn format_custom_columns() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        let options = TableOptions {
+            columns: vec![
+                Column::Total,
+                Column::Memory,
+                Column::Samples,
+                Column::Share,
+                Column::Bar,
+            ],
+            sort: SortBy::Total,
+            ..TableOptions::default()
+        };
+        update_content(&mut s, get_mock_timings(), 190.0, &options).unwrap();
+        let expected = [
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Day | Total | Memory | Samples | Share | Runtime |",
+            "| :---: | :---: | :---: | :---: | :---: | :---:  |",
+            "| [Day 4](./src/bin/04.rs) | `90.0s` | `-` | - | 47.4% | `####################` |",
+            "| [Day 2](./src/bin/02.rs) | `70.0s` | `2.0 MiB` | - | 36.8% | `################    ` |",
+            "| [Day 1](./src/bin/01.rs) | `30.0s` | `-` | 100 / 50 | 15.8% | `#######             ` |",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
 }
@@ -1,35 +1,84 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{aoc_cli, session, stars, ANSI_ITALIC, ANSI_RESET};
 use crate::Day;
 use std::fmt::Display;
 use std::io::{stdout, Write};
 use std::process::Output;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, fs, process};
 
 use super::ANSI_BOLD;
 
 pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
+    let (result, duration, samples, median) =
         run_timed(func, input, |result| print_result(result, &part_str, ""));
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(
+        &result,
+        &part_str,
+        &format_duration(&duration, samples, median.as_ref()),
+    );
 
     if let Some(result) = result {
         submit_result(result, day, part);
     }
 }
 
+/// Run the parser of a solution, so that parsing shows up as a separate timing in benchmarks.
+pub fn run_parse<I: Clone, T>(func: impl Fn(I) -> T, input: I) {
+    let (_, duration, samples, median) = run_timed(func, input, |_| print!("Parse: ✔"));
+    print!("\r");
+    println!(
+        "Parse: ✔{}",
+        format_duration(&duration, samples, median.as_ref())
+    );
+}
+
+/// Print the peak memory usage of the solution when benching. Only supported on linux.
+pub fn print_peak_memory() {
+    if !env::args().any(|x| x == "--time") {
+        return;
+    }
+
+    if let Some(kib) = peak_memory_kib() {
+        println!("Memory: {} (peak)", format_memory(kib));
+    }
+}
+
+fn peak_memory_kib() -> Option<u64> {
+    fs::read_to_string("/proc/self/status")
+        .ok()?
+        .lines()
+        .find_map(|l| l.strip_prefix("VmHWM:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()
+}
+
+fn format_memory(kib: u64) -> String {
+    if kib < 1024 {
+        format!("{kib} KiB")
+    } else {
+        #[allow(clippy::cast_precision_loss)]
+        let mib = kib as f64 / 1024_f64;
+        format!("{mib:.1} MiB")
+    }
+}
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// Returns the result, the mean duration, the sample count and the median duration when benched.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, u128, Option<Duration>) {
     let timer = Instant::now();
     let result = func(input.clone());
     let base_time = timer.elapsed();
@@ -39,25 +88,24 @@ fn run_timed<I: Clone, T>(
     let run = if std::env::args().any(|x| x == "--time") {
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        (base_time, 1, None)
     };
 
-    (result, run.0, run.1)
+    (result, run.0, run.1, run.2)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+) -> (Duration, u128, Option<Duration>) {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    let bench_iterations =
+        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -73,9 +121,20 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         #[allow(clippy::cast_possible_truncation)]
         Duration::from_nanos(average_duration(&timers) as u64),
         bench_iterations,
+        Some(median_duration(&mut timers)),
     )
 }
 
+fn median_duration(numbers: &mut [Duration]) -> Duration {
+    numbers.sort_unstable();
+    let mid = numbers.len() / 2;
+    if numbers.len() % 2 == 1 {
+        numbers[mid]
+    } else {
+        (numbers[mid - 1] + numbers[mid]) / 2
+    }
+}
+
 fn average_duration(numbers: &[Duration]) -> u128 {
     numbers
         .iter()
@@ -84,11 +143,11 @@ fn average_duration(numbers: &[Duration]) -> u128 {
         / numbers.len() as u128
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+fn format_duration(duration: &Duration, samples: u128, median: Option<&Duration>) -> String {
+    match median {
+        _ if samples == 1 => format!(" ({duration:.1?})"),
+        Some(median) => format!(" ({duration:.1?} @ {samples} samples, median {median:.1?})"),
+        None => format!(" ({duration:.1?} @ {samples} samples)"),
     }
 }
 
@@ -162,6 +221,31 @@ fn submit_result<T: Display>(
         process::exit(1);
     }
 
+    if let Err(e) = session::ensure_usable() {
+        eprintln!("Cannot submit: {e}");
+        process::exit(1);
+    }
+
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let output = aoc_cli::submit(day, part, &result.to_string());
+
+    if let Ok(output) = &output {
+        if stars::is_accepted(&String::from_utf8_lossy(&output.stdout)) {
+            record_star(day, part);
+        }
+    }
+
+    Some(output)
+}
+
+fn record_star(day: Day, part: u8) {
+    let result = stars::Stars::load().and_then(|mut stars| {
+        stars.record(day, part);
+        stars.save()
+    });
+
+    match result {
+        Ok(()) => println!("Recorded star. Run `cargo stars` to update the README."),
+        Err(e) => eprintln!("Failed to record star: {e}"),
+    }
 }
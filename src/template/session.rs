@@ -0,0 +1,293 @@
+/// Module that manages the session token used to talk to the advent of code site.
+/// The token is stored in a user-level config directory, in a file that aoc-cli can read via `--session-file`.
+/// When the token was set and when it expires is stored next to it, since the token itself does not tell.
+use std::{
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    time::SystemTime,
+};
+
+use crate::template::{datetime, http};
+
+/// Session cookies are valid for about a month. Used as the expiry if none was given.
+const ESTIMATED_LIFETIME_SECONDS: i64 = 30 * 86_400;
+
+#[derive(Debug)]
+pub enum Error {
+    NoConfigDir,
+    NotFound,
+    Expired(i64),
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NoConfigDir => write!(f, "could not determine the user config directory."),
+            Error::NotFound => write!(
+                f,
+                "no session token found. Run `cargo auth set <token>` to store one."
+            ),
+            Error::Expired(expires_at) => write!(
+                f,
+                "the session token expired on {}. Run `cargo auth set <token>` to store a new one.",
+                datetime::format_utc(datetime::from_unix_seconds(*expires_at))
+            ),
+            Error::Parser(s) => write!(f, "{s}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    pub token: String,
+    /// Seconds since the unix epoch.
+    pub set_at: i64,
+    /// Seconds since the unix epoch.
+    pub expires_at: i64,
+    /// Whether `expires_at` was given by the user or estimated.
+    pub expiry_known: bool,
+}
+
+impl Session {
+    /// Creates a session for a token that is set now. `expires_at` is estimated if not given.
+    pub fn new(token: &str, expires_at: Option<i64>) -> Self {
+        let set_at = datetime::unix_seconds(SystemTime::now());
+        Self {
+            token: token.trim().trim_start_matches("session=").to_string(),
+            set_at,
+            expires_at: expires_at.unwrap_or(set_at + ESTIMATED_LIFETIME_SECONDS),
+            expiry_known: expires_at.is_some(),
+        }
+    }
+
+    /// Whether the expiry date has passed. An estimated expiry may pass while the token is still valid.
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Fails only if the token is known to have expired.
+    fn check_expiry(&self, now: i64) -> Result<(), Error> {
+        if self.expiry_known && self.is_expired(now) {
+            Err(Error::Expired(self.expires_at))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The token with everything but its first and last four characters hidden.
+    pub fn masked_token(&self) -> String {
+        let chars: Vec<char> = self.token.chars().collect();
+        if chars.len() <= 8 {
+            return "*".repeat(chars.len());
+        }
+        let start: String = chars[..4].iter().collect();
+        let end: String = chars[chars.len() - 4..].iter().collect();
+        format!("{start}…{end}")
+    }
+
+    fn meta_to_string(&self) -> String {
+        format!(
+            "set_at={}\nexpires_at={}\nexpiry_known={}\n",
+            self.set_at, self.expires_at, self.expiry_known
+        )
+    }
+
+    fn parse(token: &str, meta: &str) -> Result<Self, Error> {
+        let value = |key: &str| {
+            meta.lines()
+                .find_map(|l| l.strip_prefix(key)?.strip_prefix('='))
+                .ok_or_else(|| Error::Parser(format!("missing `{key}` in session metadata.")))
+        };
+        let parse_err = |key: &str| Error::Parser(format!("invalid `{key}` in session metadata."));
+
+        Ok(Self {
+            token: token.trim().to_string(),
+            set_at: value("set_at")?.parse().map_err(|_| parse_err("set_at"))?,
+            expires_at: value("expires_at")?
+                .parse()
+                .map_err(|_| parse_err("expires_at"))?,
+            expiry_known: value("expiry_known")?
+                .parse()
+                .map_err(|_| parse_err("expiry_known"))?,
+        })
+    }
+}
+
+fn get_config_dir() -> Result<PathBuf, Error> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))
+        .ok_or(Error::NoConfigDir)?;
+    Ok(base.join("advent_of_code"))
+}
+
+/// Path of the file that holds the raw session token.
+pub fn get_session_path() -> Result<PathBuf, Error> {
+    Ok(get_config_dir()?.join("session"))
+}
+
+fn get_meta_path() -> Result<PathBuf, Error> {
+    Ok(get_config_dir()?.join("session.meta"))
+}
+
+/// Writes a file that only the current user can read.
+fn write_private(path: &PathBuf, contents: &str) -> Result<(), Error> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        // the mode only applies to new files, tighten permissions of existing ones as well.
+        if path.exists() {
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+        }
+    }
+
+    options.open(path)?.write_all(contents.as_bytes())?;
+    Ok(())
+}
+
+pub fn save(session: &Session) -> Result<PathBuf, Error> {
+    let dir = get_config_dir()?;
+    fs::create_dir_all(&dir)?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o700))?;
+    }
+
+    let path = get_session_path()?;
+    write_private(&path, &session.token)?;
+    write_private(&get_meta_path()?, &session.meta_to_string())?;
+    Ok(path)
+}
+
+/// Loads the stored session, returns [`Error::NotFound`] if none was stored.
+pub fn load() -> Result<Session, Error> {
+    let token = match fs::read_to_string(get_session_path()?) {
+        Ok(token) => token,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Err(Error::NotFound),
+        Err(e) => return Err(e.into()),
+    };
+
+    // tokens that were stored by other tools lack metadata, treat them as set just now.
+    match fs::read_to_string(get_meta_path()?) {
+        Ok(meta) => Session::parse(&token, &meta),
+        Err(_) => Ok(Session::new(&token, None)),
+    }
+}
+
+/// The token that aoc-cli finds on its own, see: https://github.com/scarvalhojr/aoc-cli#session-cookie-
+fn find_aoc_cli_token() -> Option<String> {
+    if let Ok(token) = env::var("ADVENT_OF_CODE_SESSION") {
+        return Some(token);
+    }
+
+    let home = env::var_os("HOME").map(|home| PathBuf::from(home).join(".adventofcode.session"));
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("adventofcode.session"));
+
+    [home, config]
+        .into_iter()
+        .flatten()
+        .find_map(|p| fs::read_to_string(p).ok())
+        .map(|token| token.trim().to_string())
+}
+
+/// Checks that a usable session token is available before talking to the site.
+/// This does not send any requests, use [`validate`] to check the token against the site.
+pub fn ensure_usable() -> Result<(), Error> {
+    match load() {
+        Ok(session) => {
+            let now = datetime::unix_seconds(SystemTime::now());
+            session.check_expiry(now)?;
+            if session.is_expired(now) {
+                eprintln!(
+                    "Warning: the session token was set on {} and may have expired. Run `cargo auth check` to check it.",
+                    datetime::format_utc(datetime::from_unix_seconds(session.set_at))
+                );
+            }
+            Ok(())
+        }
+        Err(Error::NotFound) if find_aoc_cli_token().is_some() => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the token to authenticate requests with. The stored token takes precedence over the ones aoc-cli uses.
+pub fn find_token() -> Result<String, Error> {
+    ensure_usable()?;
+    match load() {
+        Ok(session) => Ok(session.token),
+        Err(Error::NotFound) => find_aoc_cli_token().ok_or(Error::NotFound),
+        Err(e) => Err(e),
+    }
+}
+
+/// Checks the token against the site. Pages that need a login redirect when the token is invalid.
+pub fn validate(session: &Session) -> Result<bool, http::HttpError> {
+    let response = http::get("/settings", Some(&session.token))?;
+    Ok(response.is_success())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Session;
+
+    #[test]
+    fn strips_cookie_prefix() {
+        let session = Session::new(" session=53616c7465645f5f\n", None);
+        assert_eq!(session.token, "53616c7465645f5f");
+    }
+
+    #[test]
+    fn estimates_expiry() {
+        let session = Session::new("abc", None);
+        assert!(!session.expiry_known);
+        assert_eq!(session.expires_at - session.set_at, 30 * 86_400);
+        assert!(!session.is_expired(session.set_at));
+        assert!(session.is_expired(session.expires_at));
+        // an estimated expiry does not stop the token from being used.
+        assert!(session.check_expiry(session.expires_at).is_ok());
+    }
+
+    #[test]
+    fn rejects_known_expiry() {
+        let session = Session::new("abc", Some(1_703_980_800));
+        assert!(session.check_expiry(1_703_980_799).is_ok());
+        assert!(session.check_expiry(1_703_980_800).is_err());
+    }
+
+    #[test]
+    fn masks_tokens() {
+        let session = Session::new("53616c7465645f5f", Some(0));
+        assert_eq!(session.masked_token(), "5361…5f5f");
+        assert_eq!(Session::new("short", None).masked_token(), "*****");
+    }
+
+    #[test]
+    fn roundtrips_metadata() {
+        let session = Session::new("abc", Some(1_703_980_800));
+        let parsed = Session::parse("abc\n", &session.meta_to_string()).unwrap();
+        assert_eq!(parsed, session);
+        assert!(Session::parse("abc", "set_at=1").is_err());
+    }
+}
@@ -0,0 +1,289 @@
+/// Module that tracks earned stars locally and renders them to the readme.
+/// Stars are recorded when a submission is accepted, or synced from the calendar page of the event.
+/// The readme table uses the same marker-replacement approach as the benchmarking table.
+use std::{collections::BTreeMap, fmt::Display, fs, io, time::SystemTime};
+
+use crate::template::readme_benchmarks::{self, locate_table};
+use crate::template::{datetime, http, session, unlock};
+use crate::{all_days, Day};
+
+static STARS_PATH: &str = "data/.stars";
+static HEADER: &str = "# day\tpart\tearned_at";
+static MARKER: &str = "<!--- stars table --->";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    Http(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl From<readme_benchmarks::Error> for Error {
+    fn from(e: readme_benchmarks::Error) -> Self {
+        match e {
+            readme_benchmarks::Error::Parser(s) => Error::Parser(s),
+            readme_benchmarks::Error::IO(e) => Error::IO(e),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(s) | Error::Http(s) => write!(f, "{s}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// Stars earned per day and part, with the time they were recorded (seconds since the unix epoch).
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Stars {
+    earned: BTreeMap<(Day, u8), i64>,
+}
+
+impl Stars {
+    /// Loads the recorded stars. A missing file means no stars were recorded yet.
+    pub fn load() -> Result<Self, Error> {
+        match fs::read_to_string(STARS_PATH) {
+            Ok(s) => Self::parse(&s),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let mut earned = BTreeMap::new();
+
+        for line in s.lines().filter(|l| !l.is_empty() && !l.starts_with('#')) {
+            let err = || Error::Parser(format!("Malformed star entry: {line}"));
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [day, part, earned_at] = fields[..] else {
+                return Err(err());
+            };
+            let part: u8 = part
+                .parse()
+                .ok()
+                .filter(|p| *p == 1 || *p == 2)
+                .ok_or_else(err)?;
+            earned.insert(
+                (day.parse().map_err(|_| err())?, part),
+                earned_at.parse().map_err(|_| err())?,
+            );
+        }
+
+        Ok(Self { earned })
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        fs::write(STARS_PATH, self.to_string())?;
+        Ok(())
+    }
+
+    /// Records a star. Returns `false` if it was recorded already.
+    pub fn record(&mut self, day: Day, part: u8) -> bool {
+        if self.has(day, part) {
+            return false;
+        }
+        self.earned
+            .insert((day, part), datetime::unix_seconds(SystemTime::now()));
+        true
+    }
+
+    #[must_use]
+    pub fn has(&self, day: Day, part: u8) -> bool {
+        self.earned.contains_key(&(day, part))
+    }
+
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.earned.len()
+    }
+}
+
+impl Display for Stars {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        for ((day, part), earned_at) in &self.earned {
+            writeln!(f, "{day}\t{part}\t{earned_at}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether the response of aoc-cli to a submission says that the answer was accepted.
+#[must_use]
+pub fn is_accepted(response: &str) -> bool {
+    response.contains("That's the right answer")
+}
+
+/// Parses the stars per day from the calendar page of an event.
+/// Calendar days are links labelled e.g. `aria-label="Day 1, two stars"`.
+#[must_use]
+pub fn parse_calendar(html: &str) -> Vec<(Day, u8)> {
+    html.split("aria-label=\"Day ")
+        .skip(1)
+        .filter_map(|s| {
+            let (label, _) = s.split_once('"')?;
+            let (day, stars) = label.split_once(", ")?;
+            let day: Day = day.parse().ok()?;
+            match stars {
+                "two stars" => Some((day, 2)),
+                "one star" => Some((day, 1)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Records the stars shown on the calendar page of the event. Returns the number of new stars.
+pub fn sync(stars: &mut Stars) -> Result<usize, Error> {
+    let token = session::find_token().map_err(|e| Error::Http(e.to_string()))?;
+    let response = http::get(&format!("/{}", unlock::get_year()), Some(&token))
+        .map_err(|e| Error::Http(e.to_string()))?;
+
+    if !response.is_success() {
+        return Err(Error::Http(format!(
+            "the site responded with status {}.",
+            response.status
+        )));
+    }
+
+    let mut added = 0;
+    for (day, count) in parse_calendar(&response.body) {
+        for part in 1..=count {
+            if stars.record(day, part) {
+                added += 1;
+            }
+        }
+    }
+
+    Ok(added)
+}
+
+fn construct_table(prefix: &str, stars: &Stars, year: i64) -> String {
+    let header = format!("{prefix} {year} Results");
+    let star = |day: Day, part: u8| if stars.has(day, part) { "⭐" } else { "" };
+
+    let mut lines: Vec<String> = vec![
+        MARKER.into(),
+        header,
+        String::new(),
+        "| Day | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---:  |".into(),
+    ];
+
+    for day in all_days().filter(|&day| stars.has(day, 1) || stars.has(day, 2)) {
+        lines.push(format!(
+            "| [Day {}](https://adventofcode.com/{year}/day/{}) | {} | {} |",
+            day.into_inner(),
+            day.into_inner(),
+            star(day, 1),
+            star(day, 2)
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("**Total: {} ⭐**", stars.count()));
+    lines.push(MARKER.into());
+
+    lines.join("\n")
+}
+
+fn update_content(s: &mut String, stars: &Stars, year: i64) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table("##", stars, year);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+pub fn update_readme(stars: &Stars) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, stars, unlock::get_year())?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{is_accepted, parse_calendar, update_content, Stars, MARKER};
+    use crate::day;
+
+    fn get_mock_stars() -> Stars {
+        let mut stars = Stars::default();
+        stars.record(day!(1), 1);
+        stars.record(day!(1), 2);
+        stars.record(day!(3), 1);
+        stars
+    }
+
+    #[test]
+    fn records_stars_once() {
+        let mut stars = get_mock_stars();
+        assert!(!stars.record(day!(1), 1));
+        assert!(stars.record(day!(3), 2));
+        assert_eq!(stars.count(), 4);
+    }
+
+    #[test]
+    fn roundtrips_stars() {
+        let stars = get_mock_stars();
+        assert_eq!(Stars::parse(&stars.to_string()).unwrap(), stars);
+        assert!(Stars::parse("01\t3\t0").is_err());
+    }
+
+    #[test]
+    fn detects_accepted_answers() {
+        assert!(is_accepted(
+            "That's the right answer! You are one gold star closer to..."
+        ));
+        assert!(!is_accepted(
+            "That's not the right answer; your answer is too low."
+        ));
+    }
+
+    #[test]
+    fn parses_calendar() {
+        let html = r#"<a aria-label="Day 1, two stars" href="/2023/day/1" class="calendar-day1 calendar-verycomplete">
+            <a aria-label="Day 2, one star" href="/2023/day/2" class="calendar-day2 calendar-complete">
+            <a aria-label="Day 3" href="/2023/day/3" class="calendar-day3">"#;
+        assert_eq!(parse_calendar(html), vec![(day!(1), 2), (day!(2), 1)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_marker_not_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, &get_mock_stars(), 2023).unwrap();
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{}\nbar", MARKER);
+        update_content(&mut s, &get_mock_stars(), 2023).unwrap();
+        update_content(&mut s, &get_mock_stars(), 2023).unwrap();
+        let expected = [
+            "foo",
+            "<!--- stars table --->",
+            "## 2023 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 1](https://adventofcode.com/2023/day/1) | ⭐ | ⭐ |",
+            "| [Day 3](https://adventofcode.com/2023/day/3) | ⭐ |  |",
+            "",
+            "**Total: 3 ⭐**",
+            "<!--- stars table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
@@ -0,0 +1,131 @@
+/// Module that computes when puzzles unlock.
+/// Puzzles unlock at midnight EST (UTC-5). Advent happens after daylight saving time has ended, so the offset is fixed.
+use std::{
+    cmp,
+    fmt::Display,
+    io::{stdout, Write},
+    process::Output,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{aoc_cli, datetime};
+use crate::Day;
+
+const UNLOCK_OFFSET_SECONDS: i64 = 5 * 3600;
+
+/// Downloads right after unlock can fail while the site catches up, these settings keep retries polite.
+const DOWNLOAD_ATTEMPTS: u32 = 5;
+const INITIAL_BACKOFF: Duration = Duration::from_secs(2);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// The year that puzzles are solved for. Uses `AOC_YEAR` if set, the current year otherwise.
+pub fn get_year() -> i64 {
+    aoc_cli::get_year().map_or_else(datetime::current_year, i64::from)
+}
+
+/// Returns the instant the puzzle for `day` of `year` unlocks.
+pub fn unlock_time(year: i64, day: Day) -> SystemTime {
+    let days = datetime::days_from_civil(year, 12, u32::from(day.into_inner()));
+    datetime::from_unix_seconds(days * 86_400 + UNLOCK_OFFSET_SECONDS)
+}
+
+/// Returns the time left until the puzzle unlocks, or [`None`] if it is available already.
+pub fn time_until_unlock(day: Day) -> Option<Duration> {
+    unlock_time(get_year(), day)
+        .duration_since(SystemTime::now())
+        .ok()
+}
+
+/// Blocks until the puzzle for `day` has unlocked, showing a live countdown.
+pub fn wait_for_unlock(day: Day) {
+    let mut stdout = stdout();
+    let mut waited = false;
+
+    while let Some(remaining) = time_until_unlock(day) {
+        waited = true;
+        print!(
+            "\rDay {day} unlocks in {} ",
+            datetime::format_countdown(remaining)
+        );
+        let _ = stdout.flush();
+        thread::sleep(cmp::min(remaining, Duration::from_secs(1)));
+    }
+
+    if waited {
+        println!("\rDay {day} has unlocked!            ");
+    }
+}
+
+/// Calls `f` until it succeeds, doubling the delay between attempts.
+pub fn retry_with_backoff<T, E: Display>(
+    attempts: u32,
+    mut f: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let mut delay = INITIAL_BACKOFF;
+    let mut attempt = 1;
+
+    loop {
+        match f() {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt >= attempts => return Err(e),
+            Err(e) => {
+                eprintln!(
+                    "Attempt {attempt}/{attempts} failed: {e} Retrying in {}s...",
+                    delay.as_secs()
+                );
+                thread::sleep(delay);
+                delay = cmp::min(delay * 2, MAX_BACKOFF);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Waits for the puzzle to unlock, then downloads it, retrying with a backoff if the download fails.
+pub fn download_when_unlocked(
+    day: Day,
+    target: aoc_cli::DownloadTarget,
+) -> Result<Output, aoc_cli::AocCommandError> {
+    wait_for_unlock(day);
+    retry_with_backoff(DOWNLOAD_ATTEMPTS, || aoc_cli::download(day, target))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{retry_with_backoff, unlock_time};
+    use crate::{day, template::datetime::format_utc};
+
+    #[test]
+    fn unlocks_at_midnight_est() {
+        assert_eq!(
+            format_utc(unlock_time(2023, day!(1))),
+            "2023-12-01 05:00:00 UTC"
+        );
+        assert_eq!(
+            format_utc(unlock_time(2015, day!(25))),
+            "2015-12-25 05:00:00 UTC"
+        );
+    }
+
+    #[test]
+    fn stops_retrying_on_success() {
+        let mut calls = 0;
+        let result: Result<u32, &str> = retry_with_backoff(3, || {
+            calls += 1;
+            Ok(calls)
+        });
+        assert_eq!(result, Ok(1));
+    }
+
+    #[test]
+    fn gives_up_after_last_attempt() {
+        let mut calls = 0;
+        let result: Result<(), &str> = retry_with_backoff(1, || {
+            calls += 1;
+            Err("not yet.")
+        });
+        assert_eq!(result, Err("not yet."));
+        assert_eq!(calls, 1);
+    }
+}